@@ -0,0 +1,234 @@
+use std::path::PathBuf;
+use clap::{Parser, Subcommand, ValueEnum};
+use debugging::session::debug_session::LogLevel;
+use crate::conf::{AkazeConf, BriskConf, DetectorConf, MatcherAlgorithm, OrbConf, SiftConf};
+///
+/// Command line interface of the application
+#[derive(Parser, Debug)]
+#[command(version, about = "OpenCV experiments")]
+pub struct Cli {
+    /// Logging level
+    #[arg(short, long, value_enum, global = true, default_value_t = CliLogLevel::Info)]
+    pub log_level: CliLogLevel,
+    #[command(subcommand)]
+    pub command: Command,
+}
+///
+/// Experiments available from the command line
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Runs background removal pipeline headless, writing the output of each stage into the `output` directory
+    Process {
+        #[command(flatten)]
+        frames: FramesArgs,
+        #[command(flatten)]
+        pipeline: PipelineArgs,
+        /// Directory, the output of each stage will be written into
+        #[arg(short, long, default_value = "./output/")]
+        output: PathBuf,
+        /// Also write the mosaic of all outputs into `output/Mosaic/`
+        #[arg(long)]
+        mosaic: bool,
+        /// JSON file, the run summary and the latency statistics of each stage will be written into
+        #[arg(long)]
+        metrics: Option<PathBuf>,
+        /// File, the per-frame results will be written into, CSV for `.csv`, JSON Lines otherwise
+        #[arg(long)]
+        results: Option<PathBuf>,
+        #[command(flatten)]
+        mosaic_args: MosaicArgs,
+    },
+    /// Runs background removal pipeline, showing the mosaic of all outputs in the single window
+    /// 
+    /// Keys: space / p - pause, d / Right - forward, a / Left - back, f / Home - first, l / End - last,
+    /// s - save the mosaic, 1..9 - show / hide the output, q / Esc - quit
+    View {
+        #[command(flatten)]
+        frames: FramesArgs,
+        #[command(flatten)]
+        pipeline: PipelineArgs,
+        /// Show the output of each stage in it's own window instead of the mosaic
+        #[arg(long)]
+        windows: bool,
+        /// Number of the recent frames kept to step back over,
+        /// kept shrunk to the mosaic tile height, but in full resolution with `--windows`,
+        /// about 2 GB for 50 frames of 7 BGR outputs at 1920x1080
+        #[arg(long, default_value_t = 50)]
+        history: usize,
+        /// Directory, the mosaic saved into by the `s` key
+        #[arg(long, default_value = "./output/snapshots/")]
+        snapshots: PathBuf,
+        /// JSON file, the run summary and the latency statistics of each stage will be written into
+        #[arg(long)]
+        metrics: Option<PathBuf>,
+        /// File, the per-frame results will be written into, CSV for `.csv`, JSON Lines otherwise
+        #[arg(long)]
+        results: Option<PathBuf>,
+        #[command(flatten)]
+        mosaic_args: MosaicArgs,
+    },
+    /// Tunes the numeric parameters of the pipeline stages with trackbars, re-running the pipeline on change
+    Tune {
+        #[command(flatten)]
+        frames: FramesArgs,
+        #[command(flatten)]
+        pipeline: PipelineArgs,
+        /// Config file, the tuned parameters will be written into, YAML or JSON
+        #[arg(short, long, default_value = "./output/pipeline.yaml")]
+        output: PathBuf,
+        #[command(flatten)]
+        mosaic_args: MosaicArgs,
+    },
+    /// Matches the pattern image against the frames using the features detector and locates it by the homography,
+    /// the options given override the config
+    #[command(mut_arg("input", |arg| arg.default_value("./assets/input/")))]
+    Match {
+        /// Pattern image, or the directory of the pattern images to match all of them at once
+        #[arg(short, long, default_value = "./assets/patterns/pattern1.png")]
+        pattern: PathBuf,
+        #[command(flatten)]
+        frames: FramesArgs,
+        /// Directory, the frames with drawn matches will be written into, shown in the window if omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Time each frame shown in the window, ms, until the key pressed if 0, not accepted along with `output`
+        #[arg(long, default_value_t = 0, conflicts_with = "output")]
+        delay: i32,
+        #[command(flatten)]
+        match_args: MatchArgs,
+    },
+    /// Detects faces on the frames using Haar cascade classifier
+    DetectFaces {
+        #[command(flatten)]
+        frames: FramesArgs,
+        /// Haar cascade classifier file
+        #[arg(long, default_value = "/usr/share/opencv4/haarcascades/haarcascade_frontalface_default.xml")]
+        cascade: PathBuf,
+    },
+}
+///
+/// Arguments, selecting the input frames
+#[derive(clap::Args, Debug)]
+pub struct FramesArgs {
+    /// Source of the frames: directory, glob pattern of the image files, video file or `camera:<index>`
+    #[arg(short, long, default_value = "./assets/rope/")]
+    pub input: String,
+    /// Index of the first frame to be processed
+    #[arg(long, default_value_t = 0)]
+    pub start: usize,
+    /// Index of the frame, processing stops before, all frames if omitted
+    #[arg(long)]
+    pub end: Option<usize>,
+    /// Number of passes over the frames, 1 only for the capture device
+    #[arg(long, default_value_t = 1)]
+    pub loops: usize,
+}
+///
+/// Arguments of the background removal pipeline
+#[derive(clap::Args, Debug)]
+pub struct PipelineArgs {
+    /// Pipeline configuration file, YAML or JSON, like `./assets/conf/pipeline.yaml`
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+    /// Read image files as is, keeping grayscale, 16-bit depth and alpha channel, converted to 8-bit BGR otherwise
+    #[arg(long)]
+    pub unchanged: bool,
+}
+///
+/// Arguments, defining the layout of the mosaic
+#[derive(clap::Args, Debug)]
+pub struct MosaicArgs {
+    /// Height of the each tile of the mosaic, pixels
+    #[arg(long, default_value_t = 240)]
+    pub tile_height: i32,
+    /// Number of the tiles in the row of the mosaic
+    #[arg(long, default_value_t = 3)]
+    pub columns: usize,
+}
+///
+/// Parameters of the pattern matching, override the config
+#[derive(clap::Args, Debug)]
+pub struct MatchArgs {
+    /// Pattern matching config file, YAML or JSON, defaults if omitted
+    #[arg(long)]
+    pub pattern_config: Option<PathBuf>,
+    /// Features detector with the default parameters
+    #[arg(long, value_enum)]
+    pub detector: Option<CliDetector>,
+    /// Nearest neighbours search of the descriptors matching
+    #[arg(long, value_enum)]
+    pub matcher: Option<CliMatcher>,
+    /// Lowe's ratio test threshold
+    #[arg(long)]
+    pub ratio: Option<f32>,
+    /// Keeps only the matches, which are the best for both the pattern and the scene
+    #[arg(long)]
+    pub cross_check: bool,
+    /// Minimum number of the homography inliers, the pattern considered found with
+    #[arg(long)]
+    pub min_inliers: Option<usize>,
+    /// Maximum mean reprojection error of the inliers, the pattern considered found with, pixels
+    #[arg(long)]
+    pub max_error: Option<f64>,
+}
+///
+/// Logging level, selectable from the command line
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum CliLogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+//
+//
+impl From<CliLogLevel> for LogLevel {
+    fn from(value: CliLogLevel) -> Self {
+        match value {
+            CliLogLevel::Error => LogLevel::Error,
+            CliLogLevel::Warn => LogLevel::Warn,
+            CliLogLevel::Info => LogLevel::Info,
+            CliLogLevel::Debug => LogLevel::Debug,
+            CliLogLevel::Trace => LogLevel::Trace,
+        }
+    }
+}
+///
+/// Nearest neighbours search of the descriptors matching, selectable from the command line
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum CliMatcher {
+    BruteForce,
+    Flann,
+}
+//
+//
+impl From<CliMatcher> for MatcherAlgorithm {
+    fn from(value: CliMatcher) -> Self {
+        match value {
+            CliMatcher::BruteForce => MatcherAlgorithm::BruteForce,
+            CliMatcher::Flann => MatcherAlgorithm::Flann,
+        }
+    }
+}
+///
+/// Features detector, selectable from the command line
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum CliDetector {
+    Orb,
+    Akaze,
+    Brisk,
+    Sift,
+}
+//
+//
+impl From<CliDetector> for DetectorConf {
+    fn from(value: CliDetector) -> Self {
+        match value {
+            CliDetector::Orb => DetectorConf::Orb(OrbConf::default()),
+            CliDetector::Akaze => DetectorConf::Akaze(AkazeConf::default()),
+            CliDetector::Brisk => DetectorConf::Brisk(BriskConf::default()),
+            CliDetector::Sift => DetectorConf::Sift(SiftConf::default()),
+        }
+    }
+}
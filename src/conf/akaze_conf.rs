@@ -0,0 +1,93 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the AKAZE features detector and descriptor extractor,
+/// nonlinear scale space, binary MLDB or float KAZE descriptors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AkazeConf {
+    /// Type of the extracted descriptor
+    pub descriptor_type: AkazeDescriptorType,
+    /// Size of the descriptor, bits, 0 - full size
+    pub descriptor_size: i32,
+    /// Number of the channels in the descriptor, 1, 2 or 3
+    pub descriptor_channels: i32,
+    /// Detector response threshold to accept the point
+    pub threshold: f32,
+    /// Maximum octave evolution of the image
+    pub n_octaves: i32,
+    /// Number of the sublevels per scale level
+    pub n_octave_layers: i32,
+    /// Diffusivity type
+    pub diffusivity: AkazeDiffusivity,
+    /// Maximum number of the features to retain, -1 - unlimited
+    pub max_points: i32,
+}
+//
+//
+impl AkazeConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("AkazeConf", "validate");
+        if self.descriptor_size < 0 {
+            return Err(error.err(format!("descriptor-size expected >= 0, but got {}", self.descriptor_size)));
+        }
+        if !(1..=3).contains(&self.descriptor_channels) {
+            return Err(error.err(format!("descriptor-channels expected in [1, 3], but got {}", self.descriptor_channels)));
+        }
+        if self.threshold.is_nan() || self.threshold <= 0.0 {
+            return Err(error.err(format!("threshold expected > 0, but got {}", self.threshold)));
+        }
+        if self.n_octaves <= 0 || self.n_octave_layers <= 0 {
+            return Err(error.err(format!(
+                "n-octaves and n-octave-layers expected > 0, but got {}, {}",
+                self.n_octaves, self.n_octave_layers,
+            )));
+        }
+        if self.max_points == 0 || self.max_points < -1 {
+            return Err(error.err(format!("max-points expected > 0 or -1, but got {}", self.max_points)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for AkazeConf {
+    fn default() -> Self {
+        Self {
+            descriptor_type: AkazeDescriptorType::Mldb,
+            descriptor_size: 0,
+            descriptor_channels: 3,
+            threshold: 0.001,
+            n_octaves: 4,
+            n_octave_layers: 4,
+            diffusivity: AkazeDiffusivity::PmG2,
+            max_points: -1,
+        }
+    }
+}
+///
+/// Type of the AKAZE descriptor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AkazeDescriptorType {
+    /// Float, rotation invariant
+    Kaze,
+    /// Float, upright
+    KazeUpright,
+    /// Binary, rotation invariant
+    Mldb,
+    /// Binary, upright
+    MldbUpright,
+}
+///
+/// Conductance function of the AKAZE nonlinear diffusion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AkazeDiffusivity {
+    PmG1,
+    PmG2,
+    Weickert,
+    Charbonnier,
+}
@@ -0,0 +1,45 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+use super::{RoiConf, SmoothingConf};
+///
+/// Parameters of the automatic gamma correction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AutoGammaConf {
+    /// Target mean brightness of the corrected image, relative to the full range, (0, 1)
+    pub mid: f64,
+    /// Smoothing of the gamma across frames, disabled if omitted
+    pub smoothing: Option<SmoothingConf>,
+    /// Region of the image, the mean brightness calculated on, whole image if omitted
+    pub roi: Option<RoiConf>,
+}
+//
+//
+impl AutoGammaConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("AutoGammaConf", "validate");
+        if self.mid.is_nan() || self.mid <= 0.0 || self.mid >= 1.0 {
+            return Err(error.err(format!("mid expected in (0, 1), but got {}", self.mid)));
+        }
+        if let Some(smoothing) = &self.smoothing {
+            smoothing.validate()?;
+        }
+        if let Some(roi) = &self.roi {
+            roi.validate()?;
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for AutoGammaConf {
+    fn default() -> Self {
+        Self {
+            mid: 0.5,
+            smoothing: None,
+            roi: None,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the learned background persistence
+/// 
+/// The background image is stored to the `path` and fed to the background subtractor
+/// `replay` times on the next start, so the model is learned without the warm-up
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct BackgroundModelConf {
+    /// Path of the background image file
+    pub path: PathBuf,
+    /// Background image is loaded on start if exists
+    pub load: bool,
+    /// Background image is saved after the warm-up and at the end of the run
+    pub save: bool,
+    /// Number of times the loaded background image fed to the subtractor,
+    /// the length of the subtractor history by default
+    pub replay: Option<usize>,
+}
+//
+//
+impl BackgroundModelConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("BackgroundModelConf", "validate");
+        if self.path.as_os_str().is_empty() {
+            return Err(error.err("path expected not empty".to_owned()));
+        }
+        if self.replay == Some(0) {
+            return Err(error.err("replay expected > 0, but got 0".to_owned()));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for BackgroundModelConf {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("./output/background.png"),
+            load: true,
+            save: true,
+            replay: None,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+use super::{BackgroundModelConf, CntConf, GmgConf, GsocConf, KnnConf, Mog2Conf, MogConf};
+///
+/// Parameters of the background subtraction stage
+/// ```yaml
+/// - stage: background-subtraction
+///   learning-rate: 0.5
+///   warm-up-learning-rate: -1.0
+///   model:                    # optional
+///     path: ./output/background.png
+///   subtractor:
+///     kind: mog
+///     history: 100
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct BackgroundSubtractionConf {
+    /// Learning rate of the background model, [0, 1], negative means automatic
+    pub learning_rate: f64,
+    /// Learning rate of the background model during the warm-up, [0, 1], negative means automatic
+    pub warm_up_learning_rate: f64,
+    /// Persistence of the learned background, disabled if `None`
+    pub model: Option<BackgroundModelConf>,
+    /// Background subtraction algorithm and it's parameters
+    pub subtractor: SubtractorConf,
+}
+//
+//
+impl BackgroundSubtractionConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("BackgroundSubtractionConf", "validate");
+        if !(..=1.0).contains(&self.learning_rate) {
+            return Err(error.err(format!("learning-rate expected <= 1, but got {}", self.learning_rate)));
+        }
+        if !(..=1.0).contains(&self.warm_up_learning_rate) {
+            return Err(error.err(format!("warm-up-learning-rate expected <= 1, but got {}", self.warm_up_learning_rate)));
+        }
+        if let Some(model) = &self.model {
+            model.validate()?;
+        }
+        self.subtractor.validate()
+    }
+}
+//
+//
+impl Default for BackgroundSubtractionConf {
+    fn default() -> Self {
+        Self {
+            learning_rate: 0.5,
+            warm_up_learning_rate: -1.0,
+            model: None,
+            subtractor: SubtractorConf::Mog(MogConf::default()),
+        }
+    }
+}
+///
+/// Background subtraction algorithm, selected by the `kind` key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum SubtractorConf {
+    Mog(MogConf),
+    Mog2(Mog2Conf),
+    Knn(KnnConf),
+    Gmg(GmgConf),
+    Cnt(CntConf),
+    Gsoc(GsocConf),
+}
+//
+//
+impl SubtractorConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            SubtractorConf::Mog(conf) => conf.validate(),
+            SubtractorConf::Mog2(conf) => conf.validate(),
+            SubtractorConf::Knn(conf) => conf.validate(),
+            SubtractorConf::Gmg(conf) => conf.validate(),
+            SubtractorConf::Cnt(conf) => conf.validate(),
+            SubtractorConf::Gsoc(conf) => conf.validate(),
+        }
+    }
+    ///
+    /// Returns the number of frames, the subtractor needs to learn the background
+    pub fn history(&self) -> usize {
+        let frames = match self {
+            SubtractorConf::Mog(conf) => conf.history,
+            SubtractorConf::Mog2(conf) => conf.history,
+            SubtractorConf::Knn(conf) => conf.history,
+            SubtractorConf::Gmg(conf) => conf.initialization_frames,
+            SubtractorConf::Cnt(conf) => conf.min_pixel_stability,
+            SubtractorConf::Gsoc(conf) => conf.hits_threshold,
+        };
+        frames.max(1) as usize
+    }
+}
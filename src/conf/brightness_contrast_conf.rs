@@ -0,0 +1,45 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+use super::{RoiConf, SmoothingConf};
+///
+/// Parameters of the automatic brightness and contrast correction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct BrightnessContrastConf {
+    /// Percent of the histogram, clipped from both sides, [0, 100)
+    pub clip_hist_percent: f32,
+    /// Smoothing of the alpha and beta across frames, disabled if omitted
+    pub smoothing: Option<SmoothingConf>,
+    /// Region of the image, the histogram calculated on, whole image if omitted
+    pub roi: Option<RoiConf>,
+}
+//
+//
+impl BrightnessContrastConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("BrightnessContrastConf", "validate");
+        if !(0.0..100.0).contains(&self.clip_hist_percent) {
+            return Err(error.err(format!("clip-hist-percent expected in [0, 100), but got {}", self.clip_hist_percent)));
+        }
+        if let Some(smoothing) = &self.smoothing {
+            smoothing.validate()?;
+        }
+        if let Some(roi) = &self.roi {
+            roi.validate()?;
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for BrightnessContrastConf {
+    fn default() -> Self {
+        Self {
+            clip_hist_percent: 3.0,
+            smoothing: None,
+            roi: None,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the BRISK features detector and binary descriptor extractor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct BriskConf {
+    /// AGAST detection threshold score
+    pub threshold: i32,
+    /// Detection octaves, 0 - single scale
+    pub octaves: i32,
+    /// Scale of the pattern used for sampling the neighbourhood of the keypoint
+    pub pattern_scale: f32,
+}
+//
+//
+impl BriskConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("BriskConf", "validate");
+        if self.threshold < 0 {
+            return Err(error.err(format!("threshold expected >= 0, but got {}", self.threshold)));
+        }
+        if self.octaves < 0 {
+            return Err(error.err(format!("octaves expected >= 0, but got {}", self.octaves)));
+        }
+        if self.pattern_scale.is_nan() || self.pattern_scale <= 0.0 {
+            return Err(error.err(format!("pattern-scale expected > 0, but got {}", self.pattern_scale)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for BriskConf {
+    fn default() -> Self {
+        Self {
+            threshold: 30,
+            octaves: 3,
+            pattern_scale: 1.0,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the counting based background subtractor (CNT)
+/// 
+/// Uses the stability of the pixel value over the frames, very fast on the low-end hardware
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CntConf {
+    /// Number of the frames with the same pixel color to consider it stable
+    pub min_pixel_stability: i32,
+    /// Stability credit is given to the pixel for the period it was stable
+    pub use_history: bool,
+    /// Maximum credit of the stability, frames
+    pub max_pixel_stability: i32,
+    /// Frames are processed in parallel
+    pub is_parallel: bool,
+}
+//
+//
+impl CntConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("CntConf", "validate");
+        if self.min_pixel_stability <= 0 {
+            return Err(error.err(format!("min-pixel-stability expected > 0, but got {}", self.min_pixel_stability)));
+        }
+        if self.max_pixel_stability < self.min_pixel_stability {
+            return Err(error.err(format!("max-pixel-stability expected >= min-pixel-stability {}, but got {}", self.min_pixel_stability, self.max_pixel_stability)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for CntConf {
+    fn default() -> Self {
+        Self {
+            min_pixel_stability: 15,
+            use_history: true,
+            max_pixel_stability: 15 * 60,
+            is_parallel: true,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use sal_core::error::Error;
+use serde_yaml::Value;
+pub use frdm_tools::conf::DetectingContoursConf;
+///
+/// Returns error if any of the numeric parameters of the contour detection `conf` isn't finite
+/// 
+/// Only the finiteness is checked here, the meaning of the [DetectingContoursConf] values is defined by the `frdm_tools`
+pub fn validate_detecting_contours(conf: &DetectingContoursConf) -> Result<(), Error> {
+    let error = Error::new("DetectingContoursConf", "validate");
+    let value = serde_yaml::to_value(conf)
+        .map_err(|err| error.pass(err.to_string()))?;
+    validate_finite(&value, "detecting-contours")
+        .map_err(|err| error.err(err))
+}
+///
+/// Returns the message naming the `path` of the first non-finite number of the `value`
+fn validate_finite(value: &Value, path: &str) -> Result<(), String> {
+    match value {
+        Value::Number(number) => match number.as_f64() {
+            Some(number) if !number.is_finite() => Err(format!("{path} expected finite, but got {number}")),
+            _ => Ok(()),
+        },
+        Value::Mapping(map) => {
+            for (key, value) in map {
+                let key = key.as_str().map_or_else(|| format!("{:?}", key), str::to_owned);
+                validate_finite(value, &format!("{path}/{key}"))?;
+            }
+            Ok(())
+        }
+        Value::Sequence(items) => {
+            for (index, value) in items.iter().enumerate() {
+                validate_finite(value, &format!("{path}/{index}"))?;
+            }
+            Ok(())
+        }
+        Value::Tagged(tagged) => validate_finite(&tagged.value, path),
+        _ => Ok(()),
+    }
+}
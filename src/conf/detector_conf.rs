@@ -0,0 +1,38 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+use super::{AkazeConf, BriskConf, OrbConf, SiftConf};
+///
+/// Features detector and descriptor extractor, selected by the `kind` key
+/// ```yaml
+/// kind: akaze             # one of orb, akaze, brisk, sift
+/// threshold: 0.001
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum DetectorConf {
+    Orb(OrbConf),
+    Akaze(AkazeConf),
+    Brisk(BriskConf),
+    Sift(SiftConf),
+}
+//
+//
+impl DetectorConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            DetectorConf::Orb(conf) => conf.validate(),
+            DetectorConf::Akaze(conf) => conf.validate(),
+            DetectorConf::Brisk(conf) => conf.validate(),
+            DetectorConf::Sift(conf) => conf.validate(),
+        }
+    }
+}
+//
+//
+impl Default for DetectorConf {
+    fn default() -> Self {
+        DetectorConf::Orb(OrbConf::default())
+    }
+}
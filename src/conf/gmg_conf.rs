@@ -0,0 +1,50 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the GMG background subtractor
+/// 
+/// This algorithm combines statistical background image estimation and per-pixel Bayesian segmentation.
+/// 
+/// It was introduced by Andrew B. Godbehere, Akihiro Matsukawa, and Ken Goldberg in their paper
+/// "Visual Tracking of Human Visitors under Variable-Lighting Conditions for a Responsive Audio Art Installation" in 2012.
+/// As per the paper, the system ran a successful interactive audio art installation called
+/// “Are We There Yet?” from March 31 - July 31 2011 at the Contemporary Jewish Museum in San Francisco, California.
+/// 
+/// It uses first few (120 by default) frames for background modelling.
+/// It employs probabilistic foreground segmentation algorithm that identifies possible foreground objects using Bayesian inference.
+/// The estimates are adaptive; newer observations are more heavily weighted than old observations to accommodate variable illumination.
+/// You will get a black window during first few frames, unless the model is warmed up or reloaded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct GmgConf {
+    /// Number of the frames used to initialize the background model
+    pub initialization_frames: i32,
+    /// Threshold, above which the pixel is foreground, (0, 1)
+    pub decision_threshold: f64,
+}
+//
+//
+impl GmgConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("GmgConf", "validate");
+        if self.initialization_frames <= 0 {
+            return Err(error.err(format!("initialization-frames expected > 0, but got {}", self.initialization_frames)));
+        }
+        if self.decision_threshold.is_nan() || self.decision_threshold <= 0.0 || self.decision_threshold >= 1.0 {
+            return Err(error.err(format!("decision-threshold expected in (0, 1), but got {}", self.decision_threshold)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for GmgConf {
+    fn default() -> Self {
+        Self {
+            initialization_frames: 120,
+            decision_threshold: 0.8,
+        }
+    }
+}
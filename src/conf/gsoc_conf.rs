@@ -0,0 +1,74 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the GSOC background subtractor, developed during Google Summer of Code 2017,
+/// robust to the camera motion and illumination changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct GsocConf {
+    /// Camera motion compensation, 0 - none, 1 - LK
+    pub mc: i32,
+    /// Number of the samples to maintain at each point of the frame
+    pub n_samples: i32,
+    /// Probability of replacing the old sample
+    pub replace_rate: f32,
+    /// Probability of propagating to the neighbors
+    pub propagation_rate: f32,
+    /// Number of the hits, the sample becomes stable after
+    pub hits_threshold: i32,
+    /// Scale coefficient for the threshold
+    pub alpha: f32,
+    /// Bias coefficient for the threshold
+    pub beta: f32,
+    /// Blinking supression decay factor
+    pub blinking_supression_decay: f32,
+    /// Blinking supression multiplier
+    pub blinking_supression_multiplier: f32,
+    /// Strength of the noise removal for the background points
+    pub noise_removal_threshold_fac_bg: f32,
+    /// Strength of the noise removal for the foreground points
+    pub noise_removal_threshold_fac_fg: f32,
+}
+//
+//
+impl GsocConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("GsocConf", "validate");
+        if !matches!(self.mc, 0 | 1) {
+            return Err(error.err(format!("mc expected 0 or 1, but got {}", self.mc)));
+        }
+        if self.n_samples <= 0 {
+            return Err(error.err(format!("n-samples expected > 0, but got {}", self.n_samples)));
+        }
+        if self.hits_threshold <= 0 {
+            return Err(error.err(format!("hits-threshold expected > 0, but got {}", self.hits_threshold)));
+        }
+        for (name, value) in [("replace-rate", self.replace_rate), ("propagation-rate", self.propagation_rate)] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(error.err(format!("{} expected in [0, 1], but got {}", name, value)));
+            }
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for GsocConf {
+    fn default() -> Self {
+        Self {
+            mc: 0,
+            n_samples: 20,
+            replace_rate: 0.003,
+            propagation_rate: 0.01,
+            hits_threshold: 32,
+            alpha: 0.01,
+            beta: 0.0022,
+            blinking_supression_decay: 0.1,
+            blinking_supression_multiplier: 0.1,
+            noise_removal_threshold_fac_bg: 0.0004,
+            noise_removal_threshold_fac_fg: 0.0008,
+        }
+    }
+}
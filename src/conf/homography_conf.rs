@@ -0,0 +1,82 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the homography estimation from the good matches
+/// and the thresholds the pattern considered found with
+/// ```yaml
+/// method: ransac          # one of ransac, usac, usac-magsac, usac-accurate
+/// reprojection-threshold: 3.0
+/// max-iters: 2000
+/// confidence: 0.995
+/// min-inliers: 10
+/// max-error: 3.0
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct HomographyConf {
+    /// Robust estimation method
+    pub method: HomographyMethod,
+    /// Maximum distance between the projected and the matched point, the match considered inlier within, pixels
+    pub reprojection_threshold: f64,
+    /// Maximum number of the robust estimation iterations
+    pub max_iters: i32,
+    /// Confidence level of the estimation, (0, 1)
+    pub confidence: f64,
+    /// Minimum number of the inliers, the pattern considered found with
+    pub min_inliers: usize,
+    /// Maximum mean reprojection error of the inliers, the pattern considered found with, pixels
+    pub max_error: f64,
+}
+//
+//
+impl HomographyConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("HomographyConf", "validate");
+        if self.reprojection_threshold.is_nan() || self.reprojection_threshold <= 0.0 {
+            return Err(error.err(format!("reprojection-threshold expected > 0, but got {}", self.reprojection_threshold)));
+        }
+        if self.max_iters <= 0 {
+            return Err(error.err(format!("max-iters expected > 0, but got {}", self.max_iters)));
+        }
+        if self.confidence.is_nan() || self.confidence <= 0.0 || self.confidence >= 1.0 {
+            return Err(error.err(format!("confidence expected in (0, 1), but got {}", self.confidence)));
+        }
+        if self.min_inliers < 4 {
+            return Err(error.err(format!("min-inliers expected >= 4, but got {}", self.min_inliers)));
+        }
+        if self.max_error.is_nan() || self.max_error <= 0.0 {
+            return Err(error.err(format!("max-error expected > 0, but got {}", self.max_error)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for HomographyConf {
+    fn default() -> Self {
+        Self {
+            method: HomographyMethod::Ransac,
+            reprojection_threshold: 3.0,
+            max_iters: 2000,
+            confidence: 0.995,
+            min_inliers: 10,
+            max_error: 3.0,
+        }
+    }
+}
+///
+/// Robust method of the homography estimation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HomographyMethod {
+    /// Classic RANSAC
+    Ransac,
+    /// USAC with the default settings
+    Usac,
+    /// USAC with MAGSAC++ scoring
+    UsacMagsac,
+    /// USAC with the local optimization, slower but more accurate
+    UsacAccurate,
+}
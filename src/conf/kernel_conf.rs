@@ -0,0 +1,53 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Structuring element of the morphological operations
+/// ```yaml
+/// kernel:
+///     shape: ellipse
+///     width: 5
+///     height: 5
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct KernelConf {
+    /// Shape of the element
+    pub shape: KernelShape,
+    /// Width of the element, pixels
+    pub width: i32,
+    /// Height of the element, pixels
+    pub height: i32,
+}
+//
+//
+impl KernelConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("KernelConf", "validate");
+        if self.width <= 0 || self.height <= 0 {
+            return Err(error.err(format!("kernel expected width, height > 0, but got width: {}, height: {}", self.width, self.height)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for KernelConf {
+    fn default() -> Self {
+        Self {
+            shape: KernelShape::Ellipse,
+            width: 3,
+            height: 3,
+        }
+    }
+}
+///
+/// Shape of the structuring element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KernelShape {
+    Rect,
+    Ellipse,
+    Cross,
+}
@@ -0,0 +1,43 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the K-nearest neighbours based background subtractor (KNN)
+/// 
+/// Very efficient if the number of foreground pixels is low
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct KnnConf {
+    /// Length of the history, frames
+    pub history: i32,
+    /// Threshold on the squared distance between the pixel and the sample
+    pub dist2_threshold: f64,
+    /// Shadows are detected and excluded from the foreground mask
+    pub detect_shadows: bool,
+}
+//
+//
+impl KnnConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("KnnConf", "validate");
+        if self.history <= 0 {
+            return Err(error.err(format!("history expected > 0, but got {}", self.history)));
+        }
+        if self.dist2_threshold.is_nan() || self.dist2_threshold <= 0.0 {
+            return Err(error.err(format!("dist2-threshold expected > 0, but got {}", self.dist2_threshold)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for KnnConf {
+    fn default() -> Self {
+        Self {
+            history: 500,
+            dist2_threshold: 400.0,
+            detect_shadows: true,
+        }
+    }
+}
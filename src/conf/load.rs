@@ -0,0 +1,22 @@
+use std::{fmt::Debug, path::Path};
+use sal_core::error::Error;
+use serde::de::DeserializeOwned;
+///
+/// Returns the config loaded from the `path` and checked by the `validate`,
+/// JSON expected for the `.json` file, YAML otherwise
+pub fn load<T: DeserializeOwned + Debug>(path: impl AsRef<Path>, validate: impl Fn(&T) -> Result<(), Error>) -> Result<T, Error> {
+    let path = path.as_ref();
+    let error = Error::new("Conf", "load");
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| error.pass(format!("Read file '{}' error: {}", path.display(), err)))?;
+    let conf: T = match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("json") => serde_json::from_str(&text)
+            .map_err(|err| error.pass(format!("Parse file '{}' error: {}", path.display(), err)))?,
+        _ => serde_yaml::from_str(&text)
+            .map_err(|err| error.pass(format!("Parse file '{}' error: {}", path.display(), err)))?,
+    };
+    validate(&conf)
+        .map_err(|err| error.pass(format!("Invalid file '{}': {}", path.display(), err)))?;
+    log::debug!("Conf.load | '{}': {:#?}", path.display(), conf);
+    Ok(conf)
+}
@@ -0,0 +1,59 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the descriptors matching
+/// 
+/// The norm and the index are chosen by the descriptors type:
+/// Hamming / LSH for the binary descriptors, L2 / KD-tree for the float ones
+/// ```yaml
+/// algorithm: brute-force  # one of brute-force, flann
+/// ratio: 0.5              # optional, Lowe's ratio test disabled if omitted
+/// cross-check: false
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct MatcherConf {
+    /// Nearest neighbours search algorithm
+    pub algorithm: MatcherAlgorithm,
+    /// Lowe's ratio test threshold, (0, 1], the best match is kept
+    /// if its distance is below `ratio` of the second best one, disabled if `None`
+    pub ratio: Option<f32>,
+    /// The match is kept only if the pattern and the scene descriptors are the best matches of each other
+    pub cross_check: bool,
+}
+//
+//
+impl MatcherConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("MatcherConf", "validate");
+        if let Some(ratio) = self.ratio {
+            if ratio.is_nan() || ratio <= 0.0 || ratio > 1.0 {
+                return Err(error.err(format!("ratio expected in (0, 1], but got {}", ratio)));
+            }
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for MatcherConf {
+    fn default() -> Self {
+        Self {
+            algorithm: MatcherAlgorithm::BruteForce,
+            ratio: Some(0.5),
+            cross_check: false,
+        }
+    }
+}
+///
+/// Nearest neighbours search algorithm of the descriptors matching
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MatcherAlgorithm {
+    /// Exhaustive search, exact
+    BruteForce,
+    /// FLANN approximate search, LSH index for the binary descriptors, KD-tree for the float ones
+    Flann,
+}
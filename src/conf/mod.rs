@@ -0,0 +1,54 @@
+mod akaze_conf;
+mod auto_gamma_conf;
+mod background_model_conf;
+mod background_subtraction_conf;
+mod brightness_contrast_conf;
+mod brisk_conf;
+mod cnt_conf;
+mod detecting_contours_conf;
+mod detector_conf;
+mod gmg_conf;
+mod gsoc_conf;
+mod homography_conf;
+mod kernel_conf;
+mod knn_conf;
+mod load;
+mod matcher_conf;
+mod mog2_conf;
+mod mog_conf;
+mod morphology_conf;
+mod orb_conf;
+mod pattern_match_conf;
+mod pipeline_conf;
+mod roi_conf;
+mod sift_conf;
+mod smoothing_conf;
+mod stage_conf;
+mod warm_up_conf;
+pub use akaze_conf::*;
+pub use auto_gamma_conf::*;
+pub use background_model_conf::*;
+pub use background_subtraction_conf::*;
+pub use brightness_contrast_conf::*;
+pub use brisk_conf::*;
+pub use cnt_conf::*;
+pub use detecting_contours_conf::*;
+pub use detector_conf::*;
+pub use gmg_conf::*;
+pub use gsoc_conf::*;
+pub use homography_conf::*;
+pub use kernel_conf::*;
+pub use knn_conf::*;
+pub use load::*;
+pub use matcher_conf::*;
+pub use mog2_conf::*;
+pub use mog_conf::*;
+pub use morphology_conf::*;
+pub use orb_conf::*;
+pub use pattern_match_conf::*;
+pub use pipeline_conf::*;
+pub use roi_conf::*;
+pub use sift_conf::*;
+pub use smoothing_conf::*;
+pub use stage_conf::*;
+pub use warm_up_conf::*;
@@ -0,0 +1,44 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the improved Gaussian Mixture-based background subtractor (MOG2)
+/// 
+/// Selects the appropriate number of gaussian distributions for each pixel,
+/// provides better adaptability to the varying scenes due illumination changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Mog2Conf {
+    /// Length of the history, frames
+    pub history: i32,
+    /// Threshold on the squared Mahalanobis distance between the pixel and the model
+    pub var_threshold: f64,
+    /// Shadows are detected and excluded from the foreground mask
+    pub detect_shadows: bool,
+}
+//
+//
+impl Mog2Conf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("Mog2Conf", "validate");
+        if self.history <= 0 {
+            return Err(error.err(format!("history expected > 0, but got {}", self.history)));
+        }
+        if self.var_threshold.is_nan() || self.var_threshold <= 0.0 {
+            return Err(error.err(format!("var-threshold expected > 0, but got {}", self.var_threshold)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for Mog2Conf {
+    fn default() -> Self {
+        Self {
+            history: 500,
+            var_threshold: 16.0,
+            detect_shadows: true,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the Gaussian Mixture-based background subtractor (MOG)
+/// 
+/// [Adaptive background mixture models for real-time tracking](http://www.ai.mit.edu/projects/vsam/Publications/stauffer_cvpr98_track.pdf)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct MogConf {
+    /// Length of the history, frames
+    pub history: i32,
+    /// Number of the Gaussian mixtures
+    pub nmixtures: i32,
+    /// Background ratio, (0, 1]
+    pub background_ratio: f64,
+    /// Noise strength, 0 means automatic
+    pub noise_sigma: f64,
+}
+//
+//
+impl MogConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("MogConf", "validate");
+        if self.history <= 0 {
+            return Err(error.err(format!("history expected > 0, but got {}", self.history)));
+        }
+        if self.nmixtures <= 0 {
+            return Err(error.err(format!("nmixtures expected > 0, but got {}", self.nmixtures)));
+        }
+        if self.background_ratio.is_nan() || self.background_ratio <= 0.0 || self.background_ratio > 1.0 {
+            return Err(error.err(format!("background-ratio expected in (0, 1], but got {}", self.background_ratio)));
+        }
+        if !(0.0..).contains(&self.noise_sigma) {
+            return Err(error.err(format!("noise-sigma expected >= 0, but got {}", self.noise_sigma)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for MogConf {
+    fn default() -> Self {
+        Self {
+            history: 100,
+            nmixtures: 5,
+            background_ratio: 0.01,
+            noise_sigma: 0.0,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+use super::KernelConf;
+///
+/// Parameters of the morphological post-processing of the foreground mask,
+/// operations are applied in the order:
+/// ```yaml
+/// - stage: morphology
+///   kernel:
+///     shape: ellipse
+///     width: 3
+///     height: 3
+///   operations:
+///     - op: open
+///       iterations: 1
+///     - op: close
+///     - op: remove-small-blobs
+///       min-area: 50
+///     - op: fill-holes
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct MorphologyConf {
+    /// Structuring element of the open, close, dilate and erode operations
+    pub kernel: KernelConf,
+    /// Operations in the order of evaluation
+    pub operations: Vec<MorphologyOpConf>,
+}
+//
+//
+impl MorphologyConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        self.kernel.validate()?;
+        for op in &self.operations {
+            op.validate()?;
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for MorphologyConf {
+    fn default() -> Self {
+        Self {
+            kernel: KernelConf::default(),
+            operations: vec![
+                MorphologyOpConf::Open { iterations: 1 },
+                MorphologyOpConf::Close { iterations: 1 },
+            ],
+        }
+    }
+}
+///
+/// Single morphological operation, selected by the `op` key,
+/// `iterations` is 1 if omitted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "kebab-case", rename_all_fields = "kebab-case", deny_unknown_fields)]
+pub enum MorphologyOpConf {
+    /// Erosion followed by dilation, removes the small noise
+    Open {
+        #[serde(default = "MorphologyOpConf::iterations")]
+        iterations: i32,
+    },
+    /// Dilation followed by erosion, closes the small gaps
+    Close {
+        #[serde(default = "MorphologyOpConf::iterations")]
+        iterations: i32,
+    },
+    /// Grows the foreground
+    Dilate {
+        #[serde(default = "MorphologyOpConf::iterations")]
+        iterations: i32,
+    },
+    /// Shrinks the foreground
+    Erode {
+        #[serde(default = "MorphologyOpConf::iterations")]
+        iterations: i32,
+    },
+    /// Removes the connected foreground blobs with the area less than `min-area`, pixels
+    RemoveSmallBlobs { min_area: i32 },
+    /// Fills the background regions, enclosed by the foreground
+    FillHoles,
+}
+//
+//
+impl MorphologyOpConf {
+    ///
+    /// Default number of iterations
+    fn iterations() -> i32 {
+        1
+    }
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("MorphologyOpConf", "validate");
+        match self {
+            MorphologyOpConf::Open { iterations } |
+            MorphologyOpConf::Close { iterations } |
+            MorphologyOpConf::Dilate { iterations } |
+            MorphologyOpConf::Erode { iterations } => {
+                if *iterations <= 0 {
+                    return Err(error.err(format!("iterations expected > 0, but got {}", iterations)));
+                }
+            }
+            MorphologyOpConf::RemoveSmallBlobs { min_area } => {
+                if *min_area <= 0 {
+                    return Err(error.err(format!("min-area expected > 0, but got {}", min_area)));
+                }
+            }
+            MorphologyOpConf::FillHoles => {}
+        }
+        Ok(())
+    }
+}
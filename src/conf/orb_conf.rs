@@ -0,0 +1,81 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the ORB features detector and descriptor extractor
+/// 
+/// [ORB: an efficient alternative to SIFT or SURF](https://docs.opencv.org/4.x/db/d95/classcv_1_1ORB.html)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct OrbConf {
+    /// Maximum number of the features to retain
+    pub nfeatures: i32,
+    /// Pyramid decimation ratio, > 1
+    pub scale_factor: f32,
+    /// Number of the pyramid levels
+    pub nlevels: i32,
+    /// Size of the border where the features are not detected, pixels
+    pub edge_threshold: i32,
+    /// Level of the pyramid to put the source image to
+    pub first_level: i32,
+    /// Number of the points that produce each element of the descriptor, 2, 3 or 4
+    pub wta_k: i32,
+    /// Algorithm used to rank the features
+    pub score_type: OrbScoreType,
+    /// Size of the patch used by the oriented BRIEF descriptor, pixels
+    pub patch_size: i32,
+    /// FAST threshold
+    pub fast_threshold: i32,
+}
+//
+//
+impl OrbConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("OrbConf", "validate");
+        if self.nfeatures <= 0 {
+            return Err(error.err(format!("nfeatures expected > 0, but got {}", self.nfeatures)));
+        }
+        if self.scale_factor.is_nan() || self.scale_factor <= 1.0 {
+            return Err(error.err(format!("scale-factor expected > 1, but got {}", self.scale_factor)));
+        }
+        if self.nlevels <= 0 {
+            return Err(error.err(format!("nlevels expected > 0, but got {}", self.nlevels)));
+        }
+        if !(2..=4).contains(&self.wta_k) {
+            return Err(error.err(format!("wta-k expected in [2, 4], but got {}", self.wta_k)));
+        }
+        if self.patch_size <= 0 || self.edge_threshold < 0 || self.first_level < 0 || self.fast_threshold < 0 {
+            return Err(error.err(format!(
+                "patch-size expected > 0, edge-threshold, first-level, fast-threshold >= 0, but got {}, {}, {}, {}",
+                self.patch_size, self.edge_threshold, self.first_level, self.fast_threshold,
+            )));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for OrbConf {
+    fn default() -> Self {
+        Self {
+            nfeatures: 500,
+            scale_factor: 1.2,
+            nlevels: 8,
+            edge_threshold: 31,
+            first_level: 0,
+            wta_k: 2,
+            score_type: OrbScoreType::Fast,
+            patch_size: 31,
+            fast_threshold: 20,
+        }
+    }
+}
+///
+/// Algorithm used to rank the ORB features
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OrbScoreType {
+    Harris,
+    Fast,
+}
@@ -0,0 +1,50 @@
+use std::path::Path;
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+use super::{DetectorConf, HomographyConf, MatcherConf};
+///
+/// Parameters of the pattern matching
+/// 
+/// Loaded from the YAML or JSON file, omitted values are defaults:
+/// ```yaml
+/// detector:               # one of orb, akaze, brisk, sift
+///     kind: orb
+///     nfeatures: 500
+///     scale-factor: 1.2
+///     score-type: fast
+/// matcher:
+///     algorithm: brute-force
+///     ratio: 0.5
+///     cross-check: false
+/// homography:
+///     method: ransac
+///     min-inliers: 10
+///     max-error: 3.0
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PatternMatchConf {
+    /// Features detector and descriptor extractor
+    pub detector: DetectorConf,
+    /// Descriptors matching
+    pub matcher: MatcherConf,
+    /// Pattern localization
+    pub homography: HomographyConf,
+}
+//
+//
+impl PatternMatchConf {
+    ///
+    /// Returns [PatternMatchConf] loaded from the `path`,
+    /// JSON expected for the `.json` file, YAML otherwise
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        super::load(path, Self::validate)
+    }
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        self.detector.validate()?;
+        self.matcher.validate()?;
+        self.homography.validate()
+    }
+}
@@ -0,0 +1,122 @@
+use std::path::Path;
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+use super::{validate_detecting_contours, AutoGammaConf, BackgroundSubtractionConf, BrightnessContrastConf, DetectingContoursConf, MorphologyConf, StageConf, WarmUpConf};
+///
+/// Parameters of the background removal pipeline
+/// 
+/// Loaded from the YAML or JSON file, omitted values are defaults:
+/// ```yaml
+/// stages:
+///     - stage: auto-gamma
+///       mid: 0.5
+///       smoothing:            # optional
+///         factor: 0.2
+///         scene-change: 0.5
+///       roi:                  # optional, whole image by default
+///         kind: rect
+///         x: 100
+///         y: 0
+///         width: 200
+///         height: 480
+///     - stage: brightness-contrast
+///       clip-hist-percent: 3.0
+///     - stage: background-subtraction
+///       learning-rate: 0.5
+///       warm-up-learning-rate: -1.0
+///       model:                # optional, learned background persistence
+///         path: ./output/background.png
+///         load: true
+///         save: true
+///       subtractor:           # one of mog, mog2, knn, gmg, cnt, gsoc
+///         kind: mog
+///         history: 100
+///         nmixtures: 5
+///         background-ratio: 0.01
+///         noise-sigma: 0.0
+///     - stage: morphology
+///       kernel:
+///         shape: ellipse      # one of rect, ellipse, cross
+///         width: 3
+///         height: 3
+///       operations:           # open, close, dilate, erode, remove-small-blobs, fill-holes
+///         - op: open
+///           iterations: 1
+///         - op: close
+///         - op: remove-small-blobs
+///           min-area: 50
+///         - op: fill-holes
+/// warm-up:                    # optional, clean reference frames
+///     input: ./assets/background/
+///     passes: 1
+/// detecting-contours:         # `frdm_tools` DetectingContoursConf, its defaults if omitted
+///     ...
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PipelineConf {
+    /// Stages in the order of evaluation, the output of the last one goes to the contour detection
+    pub stages: Vec<StageConf>,
+    /// Warm-up from the clean reference frames, disabled if `None`
+    pub warm_up: Option<WarmUpConf>,
+    /// Contour detection, the output of the last stage goes to
+    pub detecting_contours: DetectingContoursConf,
+}
+//
+//
+impl PipelineConf {
+    ///
+    /// Returns [PipelineConf] loaded from the `path`,
+    /// JSON expected for the `.json` file, YAML otherwise
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        super::load(path, Self::validate)
+    }
+    ///
+    /// Writes [PipelineConf] into the `path`,
+    /// JSON for the `.json` file, YAML otherwise
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let error = Error::new("PipelineConf", "save");
+        let text = match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => serde_json::to_string_pretty(self)
+                .map_err(|err| error.pass(format!("Serialize '{}' error: {}", path.display(), err)))?,
+            _ => serde_yaml::to_string(self)
+                .map_err(|err| error.pass(format!("Serialize '{}' error: {}", path.display(), err)))?,
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| error.pass(format!("Create dir '{}' error: {}", dir.display(), err)))?;
+        }
+        std::fs::write(path, text)
+            .map_err(|err| error.pass(format!("Write file '{}' error: {}", path.display(), err)))?;
+        log::info!("PipelineConf.save | '{}' written", path.display());
+        Ok(())
+    }
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        for stage in &self.stages {
+            stage.validate()?;
+        }
+        if let Some(warm_up) = &self.warm_up {
+            warm_up.validate()?;
+        }
+        validate_detecting_contours(&self.detecting_contours)
+    }
+}
+//
+//
+impl Default for PipelineConf {
+    fn default() -> Self {
+        Self {
+            stages: vec![
+                StageConf::AutoGamma(AutoGammaConf::default()),
+                StageConf::BrightnessContrast(BrightnessContrastConf::default()),
+                StageConf::BackgroundSubtraction(BackgroundSubtractionConf::default()),
+                StageConf::Morphology(MorphologyConf::default()),
+            ],
+            warm_up: None,
+            detecting_contours: DetectingContoursConf::default(),
+        }
+    }
+}
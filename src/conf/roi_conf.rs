@@ -0,0 +1,48 @@
+use std::path::PathBuf;
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Region of the image, the statistics of the stage calculated on,
+/// the kind of the region selected by the `kind` key:
+/// ```yaml
+/// roi:
+///     kind: rect
+///     x: 100
+///     y: 0
+///     width: 200
+///     height: 480
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum RoiConf {
+    /// Static rectangle, pixels
+    Rect { x: i32, y: i32, width: i32, height: i32 },
+    /// Static mask image file, non-zero pixels are selected
+    Mask { path: PathBuf },
+    /// Foreground mask, produced by the pipeline: of the current frame by the preceding stage,
+    /// of the previous frame for the stages going before the background subtraction
+    Stage,
+}
+//
+//
+impl RoiConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("RoiConf", "validate");
+        match self {
+            RoiConf::Rect { x, y, width, height } => {
+                if *x < 0 || *y < 0 || *width <= 0 || *height <= 0 {
+                    return Err(error.err(format!("rect expected x, y >= 0, width, height > 0, but got x: {}, y: {}, width: {}, height: {}", x, y, width, height)));
+                }
+            }
+            RoiConf::Mask { path } => {
+                if !path.is_file() {
+                    return Err(error.err(format!("mask file '{}' not found", path.display())));
+                }
+            }
+            RoiConf::Stage => {}
+        }
+        Ok(())
+    }
+}
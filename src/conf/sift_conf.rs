@@ -0,0 +1,60 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the SIFT features detector and float descriptor extractor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct SiftConf {
+    /// Number of the best features to retain, 0 - all
+    pub nfeatures: i32,
+    /// Number of the layers in each octave
+    pub n_octave_layers: i32,
+    /// Threshold to filter out the weak features in the low-contrast regions
+    pub contrast_threshold: f64,
+    /// Threshold to filter out the edge-like features
+    pub edge_threshold: f64,
+    /// Sigma of the Gaussian applied to the input image at the octave 0
+    pub sigma: f64,
+    /// Precise upscale of the image at the octave 0
+    pub enable_precise_upscale: bool,
+}
+//
+//
+impl SiftConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("SiftConf", "validate");
+        if self.nfeatures < 0 {
+            return Err(error.err(format!("nfeatures expected >= 0, but got {}", self.nfeatures)));
+        }
+        if self.n_octave_layers <= 0 {
+            return Err(error.err(format!("n-octave-layers expected > 0, but got {}", self.n_octave_layers)));
+        }
+        if self.contrast_threshold.is_nan() || self.contrast_threshold < 0.0
+            || self.edge_threshold.is_nan() || self.edge_threshold < 0.0 {
+            return Err(error.err(format!(
+                "contrast-threshold and edge-threshold expected >= 0, but got {}, {}",
+                self.contrast_threshold, self.edge_threshold,
+            )));
+        }
+        if self.sigma.is_nan() || self.sigma <= 0.0 {
+            return Err(error.err(format!("sigma expected > 0, but got {}", self.sigma)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for SiftConf {
+    fn default() -> Self {
+        Self {
+            nfeatures: 0,
+            n_octave_layers: 3,
+            contrast_threshold: 0.04,
+            edge_threshold: 10.0,
+            sigma: 1.6,
+            enable_precise_upscale: false,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+///
+/// Parameters of the temporal smoothing of the correction values across frames
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct SmoothingConf {
+    /// Weight of the current frame value in the exponential moving average, (0, 1]
+    pub factor: f64,
+    /// Relative deviation of the current frame value from the average,
+    /// treated as the scene change, the average reset to the current value then
+    pub scene_change: f64,
+}
+//
+//
+impl SmoothingConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("SmoothingConf", "validate");
+        if self.factor.is_nan() || self.factor <= 0.0 || self.factor > 1.0 {
+            return Err(error.err(format!("factor expected in (0, 1], but got {}", self.factor)));
+        }
+        if self.scene_change.is_nan() || self.scene_change <= 0.0 {
+            return Err(error.err(format!("scene-change expected > 0, but got {}", self.scene_change)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for SmoothingConf {
+    fn default() -> Self {
+        Self {
+            factor: 0.2,
+            scene_change: 0.5,
+        }
+    }
+}
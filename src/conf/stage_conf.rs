@@ -0,0 +1,32 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+use super::{AutoGammaConf, BackgroundSubtractionConf, BrightnessContrastConf, MorphologyConf};
+///
+/// Parameters of the single stage of the pipeline,
+/// the kind of the stage selected by the `stage` key:
+/// ```yaml
+/// - stage: auto-gamma
+///   mid: 0.5
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "stage", rename_all = "kebab-case")]
+pub enum StageConf {
+    AutoGamma(AutoGammaConf),
+    BrightnessContrast(BrightnessContrastConf),
+    BackgroundSubtraction(BackgroundSubtractionConf),
+    Morphology(MorphologyConf),
+}
+//
+//
+impl StageConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            StageConf::AutoGamma(conf) => conf.validate(),
+            StageConf::BrightnessContrast(conf) => conf.validate(),
+            StageConf::BackgroundSubtraction(conf) => conf.validate(),
+            StageConf::Morphology(conf) => conf.validate(),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use sal_core::error::Error;
+use serde::{Deserialize, Serialize};
+use crate::frame_source;
+///
+/// Parameters of the warm-up phase, the clean reference frames (no foreground objects)
+/// are passed through the pipeline before the processing, so the background model is learned in advance
+/// ```yaml
+/// warm-up:
+///     input: ./assets/background/
+///     passes: 1
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct WarmUpConf {
+    /// Reference frames: directory, glob pattern of the image files or video file,
+    /// capture devices aren't accepted, they never end
+    pub input: String,
+    /// Number of passes over the reference frames
+    pub passes: usize,
+}
+//
+//
+impl WarmUpConf {
+    ///
+    /// Returns error if any of parameters out of the valid range
+    pub fn validate(&self) -> Result<(), Error> {
+        let error = Error::new("WarmUpConf", "validate");
+        if self.input.is_empty() {
+            return Err(error.err("input expected not empty".to_owned()));
+        }
+        if let Some(index) = frame_source::camera(&self.input) {
+            return Err(error.err(format!("input expected finite, but got capture device {}", index)));
+        }
+        if self.passes == 0 {
+            return Err(error.err(format!("passes expected > 0, but got {}", self.passes)));
+        }
+        Ok(())
+    }
+}
+//
+//
+impl Default for WarmUpConf {
+    fn default() -> Self {
+        Self {
+            input: "./assets/background/".to_owned(),
+            passes: 1,
+        }
+    }
+}
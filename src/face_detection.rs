@@ -0,0 +1,101 @@
+use std::path::PathBuf;
+use opencv::{
+    core, highgui, imgproc, objdetect, prelude::*
+};
+use sal_core::error::Error;
+use crate::frame_source::FrameSource;
+///
+/// Detects faces on the frames using Haar cascade classifier,
+/// detected faces are framed and shown in the window, until `q` / `Esc` pressed, the window closed or source exhausted
+pub struct FaceDetection {
+    cascade: PathBuf,
+    source: Box<dyn FrameSource>,
+}
+//
+//
+impl FaceDetection {
+    ///
+    /// Window, the frames shown in
+    const WINDOW: &'static str = "Faces";
+    ///
+    /// Returns [FaceDetection] new instance
+    /// - `cascade` - Haar cascade classifier file
+    /// - `source` - source of the frames
+    pub fn new(cascade: impl Into<PathBuf>, source: Box<dyn FrameSource>) -> Self {
+        Self {
+            cascade: cascade.into(),
+            source,
+        }
+    }
+    ///
+    /// Performs algoritm
+    pub fn eval(&mut self) -> Result<(), Error> {
+        let dbg = "FaceDetection";
+        let error = Error::new(dbg, "eval");
+        let mut face_detector = objdetect::CascadeClassifier::new(&self.cascade.to_string_lossy())
+            .map_err(|err| error.pass(err.to_string()))?;
+        highgui::named_window(Self::WINDOW, highgui::WINDOW_AUTOSIZE)
+            .map_err(|err| error.pass(err.to_string()))?;
+        while let Some(frame) = self.source.next_frame() {
+            let img = frame.and_then(|frame| {
+                let mut img = frame.mat;
+                Self::detect(&mut face_detector, &mut img)
+                    .map_err(|err| error.pass(format!("Frame {} '{}' error: {}", frame.index, frame.path.display(), err)))?;
+                Ok(img)
+            });
+            let img = match img {
+                Ok(img) => img,
+                Err(err) => {
+                    log::warn!("{dbg}.eval | {:?}", err);
+                    continue;
+                }
+            };
+            highgui::imshow(Self::WINDOW, &img)
+                .map_err(|err| error.pass(err.to_string()))?;
+            let key = highgui::wait_key(1)
+                .map_err(|err| error.pass(err.to_string()))?;
+            if key == 'q' as i32 || key == 27 {
+                break;
+            }
+            let visible = highgui::get_window_property(Self::WINDOW, highgui::WND_PROP_VISIBLE)
+                .map_err(|err| error.pass(err.to_string()))?;
+            if visible < 1.0 {
+                log::info!("{dbg}.eval | Window closed");
+                break;
+            }
+        }
+        Ok(())
+    }
+    ///
+    /// Frames the faces, detected on the `img`
+    fn detect(face_detector: &mut objdetect::CascadeClassifier, img: &mut Mat) -> Result<(), Error> {
+        let error = Error::new("FaceDetection", "detect");
+        let mut gray = Mat::default();
+        imgproc::cvt_color(img, &mut gray, imgproc::COLOR_BGR2GRAY, 0)
+            .map_err(|err| error.pass(err.to_string()))?;
+        let mut faces = core::Vector::<core::Rect>::new();
+        face_detector.detect_multi_scale(
+            &gray,
+            &mut faces,
+            1.1,
+            10,
+            objdetect::CASCADE_SCALE_IMAGE,
+            core::Size::new(50, 50),
+            core::Size::new(1000, 1000),
+        ).map_err(|err| error.pass(err.to_string()))?;
+        if !faces.is_empty() {
+            log::debug!("FaceDetection.detect | faces: {:?}", faces);
+        }
+        for face in faces {
+            imgproc::rectangle(
+                img,
+                face,
+                core::Scalar::new(0f64, 255f64, 0f64, 0f64),
+                2,
+                imgproc::LINE_8,
+                0,
+            ).map_err(|err| error.pass(err.to_string()))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use std::{path::PathBuf, time::Instant};
+use opencv::{prelude::*, videoio};
+use sal_core::error::Error;
+use super::{Frame, FrameSource};
+///
+/// Yields frames of the capture device, endless if `end` isn't specified
+/// 
+/// The device considered disconnected and the source exhausted
+/// after [CameraSource::MAX_FAILURES] consecutive failed reads
+pub struct CameraSource {
+    path: PathBuf,
+    capture: videoio::VideoCapture,
+    start: usize,
+    end: Option<usize>,
+    skip: usize,
+    index: usize,
+    failures: usize,
+    time: Instant,
+}
+//
+//
+impl CameraSource {
+    ///
+    /// Number of the consecutive failed reads, the source exhausted after
+    pub const MAX_FAILURES: usize = 10;
+    ///
+    /// Returns [CameraSource] new instance
+    /// - `index` - index of the capture device
+    /// - `start` - number of the frames to be skipped at the beginning
+    /// - `end` - index of the frame, source stops before, endless if `None`
+    pub fn new(index: i32, start: usize, end: Option<usize>) -> Result<Self, Error> {
+        let error = Error::new("CameraSource", "new");
+        let capture = videoio::VideoCapture::new(index, videoio::CAP_ANY)
+            .map_err(|err| error.pass(format!("Open camera {} error: {}", index, err)))?;
+        if !capture.is_opened().map_err(|err| error.pass(err.to_string()))? {
+            return Err(error.err(format!("Open camera {} error: can't be opened", index)));
+        }
+        Ok(Self {
+            path: PathBuf::from(format!("camera:{}", index)),
+            capture,
+            start,
+            end,
+            skip: start,
+            index: 0,
+            failures: 0,
+            time: Instant::now(),
+        })
+    }
+    ///
+    /// Counts the failed read, returns the `err`
+    fn failed(&mut self, err: Error) -> Option<Result<Frame, Error>> {
+        self.failures += 1;
+        if self.failures >= Self::MAX_FAILURES {
+            log::warn!("CameraSource.next_frame | '{}' {} reads failed in a row, considered disconnected", self.path.display(), self.failures);
+        }
+        Some(Err(err))
+    }
+}
+//
+//
+impl FrameSource for CameraSource {
+    fn next_frame(&mut self) -> Option<Result<Frame, Error>> {
+        let error = Error::new("CameraSource", "next_frame");
+        loop {
+            if self.end.is_some_and(|end| self.start + self.index >= end) || self.failures >= Self::MAX_FAILURES {
+                return None;
+            }
+            let mut mat = Mat::default();
+            match self.capture.read(&mut mat) {
+                Ok(true) if !mat.empty() => {
+                    self.failures = 0;
+                    if self.skip > 0 {
+                        self.skip -= 1;
+                        continue;
+                    }
+                    let frame = Frame {
+                        pass: 0,
+                        index: self.index,
+                        timestamp: self.time.elapsed(),
+                        path: self.path.clone(),
+                        name: format!("frame-{:06}.png", self.start + self.index),
+                        mat,
+                    };
+                    self.index += 1;
+                    return Some(Ok(frame));
+                }
+                Ok(_) => return self.failed(error.err(format!("Read '{}' error: no frame", self.path.display()))),
+                Err(err) => return self.failed(error.pass(format!("Read '{}' error: {}", self.path.display(), err))),
+            }
+        }
+    }
+}
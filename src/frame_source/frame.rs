@@ -0,0 +1,19 @@
+use std::{path::PathBuf, time::Duration};
+use opencv::core::Mat;
+///
+/// Single frame, yielded by the [FrameSource](super::FrameSource)
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// Index of the pass over the source, starting from 0
+    pub pass: usize,
+    /// Index of the frame within the pass
+    pub index: usize,
+    /// Time of the frame, position within the video file, time since the source opened otherwise
+    pub timestamp: Duration,
+    /// Path of the source, the frame belongs to
+    pub path: PathBuf,
+    /// Name of the frame, unique within the pass, file name for the image files
+    pub name: String,
+    /// Frame image
+    pub mat: Mat,
+}
@@ -0,0 +1,208 @@
+use std::{cmp::Ordering, path::{Path, PathBuf}};
+use sal_core::error::Error;
+///
+/// Single item of the [ImageSequence]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceItem {
+    /// Index of the pass over the sequence, starting from 0
+    pub pass: usize,
+    /// Index of the frame within the pass, starting from 0
+    pub index: usize,
+    /// Path of the frame file
+    pub path: PathBuf,
+}
+///
+/// Finite sequence of the image files
+/// 
+/// - Files are ordered naturally, so `image-35.png` goes before `image-140.png`
+/// - Only files in the range `start..end` are yielded
+/// - The whole range is yielded `passes` times, then sequence terminates
+#[derive(Debug, Clone)]
+pub struct ImageSequence {
+    paths: Vec<PathBuf>,
+    passes: usize,
+    pass: usize,
+    index: usize,
+}
+//
+//
+impl ImageSequence {
+    ///
+    /// Returns [ImageSequence] new instance
+    /// - `dir` - directory containing image files
+    /// - `start` - index of the first file to be yielded
+    /// - `end` - index of the file, sequence stops before, all files if `None`
+    /// - `passes` - number of passes over the range
+    pub fn new(dir: impl AsRef<Path>, start: usize, end: Option<usize>, passes: usize) -> Result<Self, Error> {
+        let dir = dir.as_ref();
+        let error = Error::new("ImageSequence", "new");
+        let entries = std::fs::read_dir(dir)
+            .map_err(|err| error.pass(format!("Read dir '{}' error: {}", dir.display(), err)))?;
+        let paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .collect();
+        log::debug!("ImageSequence.new | dir '{}'", dir.display());
+        Ok(Self::from_paths(paths, start, end, passes))
+    }
+    ///
+    /// Returns [ImageSequence] new instance
+    /// - `paths` - image files, will be ordered naturally
+    /// - `start` - index of the first file to be yielded
+    /// - `end` - index of the file, sequence stops before, all files if `None`
+    /// - `passes` - number of passes over the range
+    pub fn from_paths(mut paths: Vec<PathBuf>, start: usize, end: Option<usize>, passes: usize) -> Self {
+        paths.sort_by(|a, b| natural_cmp(&a.to_string_lossy(), &b.to_string_lossy()));
+        let end = end.unwrap_or(paths.len()).min(paths.len());
+        let paths: Vec<PathBuf> = paths.drain(start.min(end)..end).collect();
+        log::debug!("ImageSequence.from_paths | {} files in range {}..{}, {} passes", paths.len(), start, end, passes);
+        Self {
+            paths,
+            passes,
+            pass: 0,
+            index: 0,
+        }
+    }
+}
+//
+//
+impl Iterator for ImageSequence {
+    type Item = SequenceItem;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.paths.len() {
+            self.index = 0;
+            self.pass += 1;
+        }
+        if self.pass >= self.passes || self.paths.is_empty() {
+            return None;
+        }
+        let item = SequenceItem {
+            pass: self.pass,
+            index: self.index,
+            path: self.paths[self.index].clone(),
+        };
+        self.index += 1;
+        Some(item)
+    }
+}
+///
+/// Compares strings in the natural order,
+/// digit groups are compared as the numbers, so `image-35.png` goes before `image-140.png`
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(ca), Some(cb)) if ca.is_ascii_digit() && cb.is_ascii_digit() => {
+                let na = take_digits(&mut a);
+                let nb = take_digits(&mut b);
+                let ordering = na.trim_start_matches('0').len().cmp(&nb.trim_start_matches('0').len())
+                    .then_with(|| na.trim_start_matches('0').cmp(nb.trim_start_matches('0')))
+                    .then_with(|| na.len().cmp(&nb.len()));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(ca), Some(cb)) => {
+                if ca != cb {
+                    return ca.cmp(&cb);
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+///
+/// Consumes and returns leading digits of the `chars`
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+        digits.push(c);
+    }
+    digits
+}
+#[cfg(test)]
+mod tests {
+    use std::{cmp::Ordering, path::PathBuf};
+    use super::{natural_cmp, ImageSequence};
+    ///
+    /// Returns paths of the `names`
+    fn paths(names: &[&str]) -> Vec<PathBuf> {
+        names.iter().map(PathBuf::from).collect()
+    }
+    ///
+    /// Returns `(pass, index, file name)` of each item yielded by the `sequence`
+    fn items(sequence: ImageSequence) -> Vec<(usize, usize, String)> {
+        sequence.map(|item| (item.pass, item.index, item.path.to_string_lossy().into_owned())).collect()
+    }
+    #[test]
+    fn natural_cmp_numbers() {
+        assert_eq!(natural_cmp("image-35.png", "image-140.png"), Ordering::Less);
+        assert_eq!(natural_cmp("image-140.png", "image-35.png"), Ordering::Greater);
+        assert_eq!(natural_cmp("image-9.png", "image-10.png"), Ordering::Less);
+        assert_eq!(natural_cmp("image-35.png", "image-35.png"), Ordering::Equal);
+        assert_eq!(natural_cmp("a-2.png", "b-1.png"), Ordering::Less);
+        assert_eq!(natural_cmp("image-2", "image-2.png"), Ordering::Less);
+    }
+    #[test]
+    fn natural_cmp_leading_zeros() {
+        assert_eq!(natural_cmp("image-007.png", "image-8.png"), Ordering::Less);
+        assert_eq!(natural_cmp("image-010.png", "image-9.png"), Ordering::Greater);
+        // Same number, the shorter one goes first
+        assert_eq!(natural_cmp("image-7.png", "image-007.png"), Ordering::Less);
+        assert_eq!(natural_cmp("image-007.png", "image-07.png"), Ordering::Greater);
+    }
+    #[test]
+    fn order() {
+        let sequence = ImageSequence::from_paths(paths(&["image-140.png", "image-35.png", "image-21.png", "image-110.png"]), 0, None, 1);
+        assert_eq!(items(sequence), vec![
+            (0, 0, "image-21.png".to_owned()),
+            (0, 1, "image-35.png".to_owned()),
+            (0, 2, "image-110.png".to_owned()),
+            (0, 3, "image-140.png".to_owned()),
+        ]);
+    }
+    #[test]
+    fn range() {
+        let names = ["image-1.png", "image-2.png", "image-3.png", "image-4.png"];
+        let sequence = ImageSequence::from_paths(paths(&names), 1, Some(3), 1);
+        assert_eq!(items(sequence), vec![
+            (0, 0, "image-2.png".to_owned()),
+            (0, 1, "image-3.png".to_owned()),
+        ]);
+        // `end` beyond the files is clamped
+        let sequence = ImageSequence::from_paths(paths(&names), 2, Some(100), 1);
+        assert_eq!(items(sequence).len(), 2);
+        // `start` beyond the `end` or the files yields nothing
+        let sequence = ImageSequence::from_paths(paths(&names), 3, Some(2), 1);
+        assert!(items(sequence).is_empty());
+        let sequence = ImageSequence::from_paths(paths(&names), 10, None, 1);
+        assert!(items(sequence).is_empty());
+    }
+    #[test]
+    fn passes() {
+        let names = ["image-2.png", "image-1.png"];
+        let sequence = ImageSequence::from_paths(paths(&names), 0, None, 0);
+        assert!(items(sequence).is_empty());
+        let sequence = ImageSequence::from_paths(paths(&names), 0, None, 1);
+        assert_eq!(items(sequence), vec![
+            (0, 0, "image-1.png".to_owned()),
+            (0, 1, "image-2.png".to_owned()),
+        ]);
+        let sequence = ImageSequence::from_paths(paths(&names), 0, None, 2);
+        assert_eq!(items(sequence), vec![
+            (0, 0, "image-1.png".to_owned()),
+            (0, 1, "image-2.png".to_owned()),
+            (1, 0, "image-1.png".to_owned()),
+            (1, 1, "image-2.png".to_owned()),
+        ]);
+        // Exhausted sequence stays exhausted
+        let mut sequence = ImageSequence::from_paths(paths(&names), 0, None, 1);
+        assert_eq!(sequence.by_ref().count(), 2);
+        assert_eq!(sequence.next(), None);
+    }
+}
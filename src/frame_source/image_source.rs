@@ -0,0 +1,72 @@
+use std::{path::Path, time::Instant};
+use opencv::{imgcodecs, prelude::*};
+use sal_core::error::Error;
+use super::{Frame, FrameSource, ImageSequence};
+///
+/// Yields image files of the directory or matching glob pattern, ordered naturally
+pub struct ImageSource {
+    sequence: ImageSequence,
+    flags: i32,
+    time: Instant,
+}
+//
+//
+impl ImageSource {
+    ///
+    /// Returns [ImageSource] new instance, yielding image files of the directory
+    /// - `dir` - directory containing image files
+    /// - `start` - index of the first file to be yielded
+    /// - `end` - index of the file, source stops before, all files if `None`
+    /// - `passes` - number of passes over the files
+    /// - `flags` - `imgcodecs::IMREAD_*` flags, the files are read with
+    pub fn dir(dir: impl AsRef<Path>, start: usize, end: Option<usize>, passes: usize, flags: i32) -> Result<Self, Error> {
+        Ok(Self {
+            sequence: ImageSequence::new(dir, start, end, passes)?,
+            flags,
+            time: Instant::now(),
+        })
+    }
+    ///
+    /// Returns [ImageSource] new instance, yielding image files matching glob `pattern`
+    /// - `pattern` - glob pattern, like `./assets/rope/image-*.png`
+    /// - `start` - index of the first file to be yielded
+    /// - `end` - index of the file, source stops before, all files if `None`
+    /// - `passes` - number of passes over the files
+    /// - `flags` - `imgcodecs::IMREAD_*` flags, the files are read with
+    pub fn glob(pattern: &str, start: usize, end: Option<usize>, passes: usize, flags: i32) -> Result<Self, Error> {
+        let error = Error::new("ImageSource", "glob");
+        let paths = glob::glob(pattern)
+            .map_err(|err| error.pass(format!("Pattern '{}' error: {}", pattern, err)))?
+            .filter_map(|path| path.ok())
+            .filter(|path| path.is_file())
+            .collect();
+        Ok(Self {
+            sequence: ImageSequence::from_paths(paths, start, end, passes),
+            flags,
+            time: Instant::now(),
+        })
+    }
+}
+//
+//
+impl FrameSource for ImageSource {
+    fn next_frame(&mut self) -> Option<Result<Frame, Error>> {
+        let error = Error::new("ImageSource", "next_frame");
+        let item = self.sequence.next()?;
+        let timestamp = self.time.elapsed();
+        let path = item.path.to_string_lossy();
+        let mat = match imgcodecs::imread(&path, self.flags) {
+            Ok(mat) if !mat.empty() => mat,
+            Ok(_) => return Some(Err(error.err(format!("Read file '{}' error: empty image", path)))),
+            Err(err) => return Some(Err(error.pass(format!("Read file '{}' error: {}", path, err)))),
+        };
+        Some(Ok(Frame {
+            pass: item.pass,
+            index: item.index,
+            timestamp,
+            name: item.path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default(),
+            path: item.path,
+            mat,
+        }))
+    }
+}
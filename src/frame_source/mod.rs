@@ -0,0 +1,57 @@
+mod camera_source;
+mod frame;
+mod image_sequence;
+mod image_source;
+mod video_source;
+pub use camera_source::*;
+pub use frame::*;
+pub use image_sequence::*;
+pub use image_source::*;
+pub use video_source::*;
+use std::path::Path;
+use opencv::imgcodecs;
+use sal_core::error::Error;
+///
+/// Source of the frames, feeding algorithms
+pub trait FrameSource {
+    ///
+    /// Returns next frame,
+    /// - `Some(Err(_))` if the frame can't be read, following frames still can be requested
+    /// - `None` if the source exhausted
+    fn next_frame(&mut self) -> Option<Result<Frame, Error>>;
+}
+///
+/// Returns [FrameSource] recognized from the `input`:
+/// - `camera:<index>` or `<index>` - capture device
+/// - path containing `*`, `?` or `[` - glob pattern of the image files
+/// - path to the directory - image files of the directory
+/// - path to the file - video file
+/// 
+/// Parameters:
+/// - `start` - index of the first frame to be yielded
+/// - `end` - index of the frame, source stops before, all frames if `None`
+/// - `passes` - number of passes over the frames, the capture devices accept 1 only
+/// - `unchanged` - image files are read as is, keeping grayscale, 16-bit depth and alpha channel,
+///   converted to 8-bit BGR otherwise, ignored by the video files and capture devices
+pub fn open(input: &str, start: usize, end: Option<usize>, passes: usize, unchanged: bool) -> Result<Box<dyn FrameSource>, Error> {
+    let flags = if unchanged { imgcodecs::IMREAD_UNCHANGED } else { imgcodecs::IMREAD_COLOR };
+    if let Some(index) = camera(input) {
+        if passes != 1 {
+            let error = Error::new("frame_source", "open");
+            return Err(error.err(format!("passes expected 1 for the capture device {}, but got {}", index, passes)));
+        }
+        return Ok(Box::new(CameraSource::new(index, start, end)?));
+    }
+    if input.contains(['*', '?', '[']) {
+        return Ok(Box::new(ImageSource::glob(input, start, end, passes, flags)?));
+    }
+    if Path::new(input).is_dir() {
+        return Ok(Box::new(ImageSource::dir(input, start, end, passes, flags)?));
+    }
+    Ok(Box::new(VideoSource::new(input, start, end, passes)?))
+}
+///
+/// Returns the index of the capture device if the `input` is `camera:<index>` or `<index>`
+pub fn camera(input: &str) -> Option<i32> {
+    input.strip_prefix("camera:").unwrap_or(input).parse::<i32>().ok()
+}
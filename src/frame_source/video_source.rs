@@ -0,0 +1,119 @@
+use std::{path::{Path, PathBuf}, time::Duration};
+use opencv::{prelude::*, videoio};
+use sal_core::error::Error;
+use super::{Frame, FrameSource};
+///
+/// Yields frames of the video file
+/// 
+/// The file considered broken and the source exhausted
+/// after [VideoSource::MAX_FAILURES] consecutive failed reads
+pub struct VideoSource {
+    path: PathBuf,
+    capture: videoio::VideoCapture,
+    start: usize,
+    end: Option<usize>,
+    passes: usize,
+    pass: usize,
+    index: usize,
+    failures: usize,
+}
+//
+//
+impl VideoSource {
+    ///
+    /// Number of the consecutive failed reads, the source exhausted after
+    pub const MAX_FAILURES: usize = 10;
+    ///
+    /// Returns [VideoSource] new instance
+    /// - `path` - video file
+    /// - `start` - index of the first frame to be yielded
+    /// - `end` - index of the frame, source stops before, all frames if `None`
+    /// - `passes` - number of passes over the frames
+    pub fn new(path: impl AsRef<Path>, start: usize, end: Option<usize>, passes: usize) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let error = Error::new("VideoSource", "new");
+        let capture = videoio::VideoCapture::from_file(&path.to_string_lossy(), videoio::CAP_ANY)
+            .map_err(|err| error.pass(format!("Open video '{}' error: {}", path.display(), err)))?;
+        if !capture.is_opened().map_err(|err| error.pass(err.to_string()))? {
+            return Err(error.err(format!("Open video '{}' error: can't be opened", path.display())));
+        }
+        let mut source = Self {
+            path,
+            capture,
+            start,
+            end,
+            passes,
+            pass: 0,
+            index: 0,
+            failures: 0,
+        };
+        source.rewind()?;
+        Ok(source)
+    }
+    ///
+    /// Sets the position of the video to the `start` frame
+    fn rewind(&mut self) -> Result<(), Error> {
+        let error = Error::new("VideoSource", "rewind");
+        self.index = 0;
+        self.capture.set(videoio::CAP_PROP_POS_FRAMES, self.start as f64)
+            .map_err(|err| error.pass(format!("Seek video '{}' error: {}", self.path.display(), err)))?;
+        Ok(())
+    }
+}
+//
+//
+impl FrameSource for VideoSource {
+    fn next_frame(&mut self) -> Option<Result<Frame, Error>> {
+        let error = Error::new("VideoSource", "next_frame");
+        loop {
+            if self.pass >= self.passes {
+                return None;
+            }
+            let exhausted = match self.end {
+                Some(end) if self.start + self.index >= end => true,
+                _ => {
+                    let mut mat = Mat::default();
+                    match self.capture.read(&mut mat) {
+                        Ok(true) if !mat.empty() => {
+                            self.failures = 0;
+                            let timestamp = self.capture.get(videoio::CAP_PROP_POS_MSEC).unwrap_or_default();
+                            let frame = Frame {
+                                pass: self.pass,
+                                index: self.index,
+                                timestamp: Duration::from_secs_f64(timestamp.max(0.0) / 1000.0),
+                                path: self.path.clone(),
+                                name: format!("frame-{:06}.png", self.start + self.index),
+                                mat,
+                            };
+                            self.index += 1;
+                            return Some(Ok(frame));
+                        }
+                        Ok(_) => true,
+                        Err(err) => {
+                            self.index += 1;
+                            self.failures += 1;
+                            if self.failures >= Self::MAX_FAILURES {
+                                log::warn!("VideoSource.next_frame | Video '{}' {} reads failed in a row, considered broken", self.path.display(), self.failures);
+                                self.pass = self.passes;
+                            }
+                            return Some(Err(error.pass(format!("Read video '{}' frame {} error: {}", self.path.display(), self.start + self.index - 1, err))));
+                        }
+                    }
+                }
+            };
+            if exhausted {
+                if self.index == 0 {
+                    log::warn!("VideoSource.next_frame | Video '{}' has no frames in the range", self.path.display());
+                    return None;
+                }
+                self.pass += 1;
+                if self.pass < self.passes {
+                    if let Err(err) = self.rewind() {
+                        self.pass = self.passes;
+                        return Some(Err(err));
+                    }
+                }
+            }
+        }
+    }
+}
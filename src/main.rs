@@ -1,9 +1,103 @@
+mod cli;
+mod conf;
+mod face_detection;
+mod frame_source;
+mod match_pattern;
+mod metrics;
+mod mosaic;
+mod pattern_match;
 mod remove_background;
-use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
-use crate::remove_background::RemoveBackground;
+mod results;
+mod run_summary;
+mod stage;
+mod tuning;
+mod viewer;
+use clap::Parser;
+use debugging::session::debug_session::{Backtrace, DebugSession};
+use sal_core::error::Error;
+use crate::{
+    cli::{Cli, Command, FramesArgs, MatchArgs, MosaicArgs, PipelineArgs},
+    conf::{PatternMatchConf, PipelineConf},
+    face_detection::FaceDetection,
+    frame_source::FrameSource,
+    match_pattern::MatchPattern,
+    mosaic::Mosaic,
+    remove_background::RemoveBackground,
+    viewer::Viewer,
+};
 
 fn main() {
-    DebugSession::init(LogLevel::Debug, Backtrace::Short);
-    let remove_background = RemoveBackground::new();
-    remove_background.eval().unwrap();
+    let cli = Cli::parse();
+    DebugSession::init(cli.log_level.into(), Backtrace::Short);
+    let result = match cli.command {
+        Command::Process { frames, pipeline, output, mosaic, metrics, results, mosaic_args } => remove_background(&frames, &pipeline)
+            .map(|rb| if mosaic { rb.mosaic(self::mosaic(&mosaic_args)) } else { rb })
+            .map(|rb| match metrics { Some(path) => rb.metrics(path), None => rb })
+            .and_then(|rb| match results { Some(path) => rb.results(path), None => Ok(rb) })
+            .and_then(|mut rb| rb.eval(output)),
+        Command::View { frames, pipeline, windows, history, snapshots, metrics, results, mosaic_args } => remove_background(&frames, &pipeline)
+            .map(|rb| match metrics { Some(path) => rb.metrics(path), None => rb })
+            .and_then(|rb| match results { Some(path) => rb.results(path), None => Ok(rb) })
+            .and_then(|mut rb| rb.view(Viewer::new(self::mosaic(&mosaic_args), windows, history, snapshots))),
+        Command::Tune { frames, pipeline, output, mosaic_args } => remove_background(&frames, &pipeline)
+            .map(|rb| rb.mosaic(self::mosaic(&mosaic_args)))
+            .and_then(|mut rb| rb.tune(output)),
+        Command::Match { pattern, frames, output, delay, match_args } => pattern_match_conf(&match_args)
+            .and_then(|conf| frame_source(&frames, false).map(|source| MatchPattern::new(pattern, source, conf)))
+            .and_then(|mut mp| mp.eval(output, delay)),
+        Command::DetectFaces { frames, cascade } => frame_source(&frames, false).and_then(|source| FaceDetection::new(cascade, source).eval()),
+    };
+    if let Err(err) = result {
+        log::error!("main | Error: {:?}", err);
+        std::process::exit(1);
+    }
+}
+///
+/// Returns [RemoveBackground] configured from the command line
+fn remove_background(frames: &FramesArgs, pipeline: &PipelineArgs) -> Result<RemoveBackground, Error> {
+    let conf = match &pipeline.config {
+        Some(path) => PipelineConf::load(path)?,
+        None => PipelineConf::default(),
+    };
+    RemoveBackground::new(frame_source(frames, pipeline.unchanged)?, conf)
+}
+///
+/// Returns [PatternMatchConf] loaded from the config if specified,
+/// overridden by the command line options
+fn pattern_match_conf(args: &MatchArgs) -> Result<PatternMatchConf, Error> {
+    let mut conf = match &args.pattern_config {
+        Some(path) => PatternMatchConf::load(path)?,
+        None => PatternMatchConf::default(),
+    };
+    if let Some(detector) = args.detector {
+        conf.detector = detector.into();
+    }
+    if let Some(matcher) = args.matcher {
+        conf.matcher.algorithm = matcher.into();
+    }
+    if let Some(ratio) = args.ratio {
+        conf.matcher.ratio = Some(ratio);
+    }
+    if args.cross_check {
+        conf.matcher.cross_check = true;
+    }
+    if let Some(min_inliers) = args.min_inliers {
+        conf.homography.min_inliers = min_inliers;
+    }
+    if let Some(max_error) = args.max_error {
+        conf.homography.max_error = max_error;
+    }
+    conf.validate()?;
+    Ok(conf)
+}
+///
+/// Returns [Mosaic] configured from the command line
+fn mosaic(args: &MosaicArgs) -> Mosaic {
+    Mosaic::new(args.tile_height, args.columns)
+}
+///
+/// Returns [FrameSource] selected from the command line
+/// - `unchanged` - image files are read as is, see [frame_source::open]
+fn frame_source(frames: &FramesArgs, unchanged: bool) -> Result<Box<dyn FrameSource>, Error> {
+    frame_source::open(&frames.input, frames.start, frames.end, frames.loops, unchanged)
 }
@@ -0,0 +1,152 @@
+use std::path::{Path, PathBuf};
+use opencv::{core, highgui, imgcodecs, prelude::*};
+use sal_core::error::Error;
+use crate::{
+    conf::PatternMatchConf,
+    frame_source::{Frame, FrameSource},
+    pattern_match::{Pattern, PatternLibrary, PatternMatcher},
+};
+///
+/// Matches the pattern image against the frames of the source using [PatternMatcher],
+/// the good matches and the located pattern are drawn and shown in the window or written into the files
+/// 
+/// If the pattern is the directory, all the images of it are loaded into the [PatternLibrary]
+/// and the patterns found on the frame are outlined
+pub struct MatchPattern {
+    pattern: PathBuf,
+    source: Box<dyn FrameSource>,
+    conf: PatternMatchConf,
+}
+//
+//
+impl MatchPattern {
+    ///
+    /// Name of the window, the results shown in
+    const WINDOW: &'static str = "Match";
+    ///
+    /// Returns [MatchPattern] new instance
+    /// - `pattern` - pattern image, or the directory of the pattern images
+    /// - `source` - source of the frames, the pattern will be searched in
+    /// - `conf` - matching parameters
+    pub fn new(pattern: impl Into<PathBuf>, source: Box<dyn FrameSource>, conf: PatternMatchConf) -> Self {
+        Self {
+            pattern: pattern.into(),
+            source,
+            conf,
+        }
+    }
+    ///
+    /// Performs algoritm until the source exhausted
+    /// - `output` - directory, the images with drawn matches will be written into, shown in the window if `None`
+    /// - `delay` - time each frame shown in the window, ms, until the key pressed if 0,
+    ///   `q` / `Esc` or closing the window quits
+    /// 
+    /// The frame, failed to be read or matched, is logged and skipped
+    pub fn eval(&mut self, output: Option<PathBuf>, delay: i32) -> Result<(), Error> {
+        let dbg = "MatchPattern";
+        let error = Error::new(dbg, "eval");
+        self.conf.validate()?;
+        let mut matcher = PatternMatcher::new(self.conf.clone())?;
+        let patterns = if self.pattern.is_dir() {
+            Patterns::Library(PatternLibrary::load(&self.pattern, &mut matcher)?)
+        } else {
+            let img = Self::read(&self.pattern)?;
+            let name = self.pattern.file_stem().map_or_else(|| self.pattern.to_string_lossy(), |stem| stem.to_string_lossy()).into_owned();
+            let pattern = matcher.pattern(name, &img)?;
+            Patterns::Single(img, pattern)
+        };
+        match &output {
+            Some(output) => std::fs::create_dir_all(output)
+                .map_err(|err| error.pass(format!("Create dir '{}' error: {}", output.display(), err)))?,
+            None => highgui::named_window(Self::WINDOW, highgui::WINDOW_NORMAL)
+                .map_err(|err| error.pass(err.to_string()))?,
+        }
+        while let Some(frame) = self.source.next_frame() {
+            let result = frame.and_then(|frame| {
+                let img = match &patterns {
+                    Patterns::Single(img, pattern) => Self::single(&mut matcher, img, pattern, &frame),
+                    Patterns::Library(library) => Self::library(&mut matcher, library, &frame),
+                };
+                img.map(|img| (frame.name.clone(), img))
+                    .map_err(|err| error.pass(format!("Frame {} '{}' error: {}", frame.index, frame.path.display(), err)))
+            });
+            let (name, img) = match result {
+                Ok(result) => result,
+                Err(err) => {
+                    log::warn!("{dbg}.eval | {:?}", err);
+                    continue;
+                }
+            };
+            match &output {
+                Some(output) => {
+                    let path = output.join(&name);
+                    imgcodecs::imwrite(&path.to_string_lossy(), &img, &core::Vector::new())
+                        .map_err(|err| error.pass(format!("Write '{}' error: {}", path.display(), err)))?;
+                    log::info!("{dbg}.eval | written: {}", path.display());
+                }
+                None => {
+                    highgui::imshow(Self::WINDOW, &img)
+                        .map_err(|err| error.pass(err.to_string()))?;
+                    let key = highgui::wait_key(delay)
+                        .map_err(|err| error.pass(err.to_string()))?;
+                    // -1 returned immediately once the window closed
+                    if key == 'q' as i32 || key == 27 || (delay <= 0 && key == -1) {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+    ///
+    /// Returns the pattern and the frame side by side with the good matches drawn
+    fn single(matcher: &mut PatternMatcher, img: &Mat, pattern: &Pattern, frame: &Frame) -> Result<Mat, Error> {
+        let dbg = "MatchPattern";
+        let result = matcher.eval_pattern(pattern, &frame.mat)?;
+        match &result.location {
+            Some(location) => log::info!(
+                "{dbg}.single | '{}' found: {}, good matches: {}, score: {:.3}, confidence: {:.3}, inliers: {}, error: {:.3}, corners: {:?}",
+                frame.name, result.found, result.good_matches.len(), result.score, result.confidence, location.inliers, location.error, location.corners,
+            ),
+            None => log::info!("{dbg}.single | '{}' found: false, good matches: {}, score: {:.3}, not located", frame.name, result.good_matches.len(), result.score),
+        }
+        result.draw(img, &frame.mat)
+    }
+    ///
+    /// Returns the frame with all the patterns of the `library`, found on it, outlined
+    fn library(matcher: &mut PatternMatcher, library: &PatternLibrary, frame: &Frame) -> Result<Mat, Error> {
+        let dbg = "MatchPattern";
+        let error = Error::new(dbg, "library");
+        let detections = matcher.eval_library(library, &frame.mat)?;
+        log::info!("{dbg}.library | '{}' found {} of {} patterns", frame.name, detections.len(), library.patterns().len());
+        let mut img = frame.mat.try_clone().map_err(|err| error.pass(err.to_string()))?;
+        for detection in &detections {
+            log::info!(
+                "{dbg}.library | '{}' confidence: {:.3}, inliers: {}, error: {:.3}, corners: {:?}",
+                detection.name, detection.confidence, detection.location.inliers, detection.location.error, detection.location.corners,
+            );
+            detection.draw(&mut img)?;
+        }
+        Ok(img)
+    }
+    ///
+    /// Returns the image read from the `path`
+    fn read(path: &Path) -> Result<Mat, Error> {
+        let error = Error::new("MatchPattern", "read");
+        let img = imgcodecs::imread(&path.to_string_lossy(), imgcodecs::IMREAD_COLOR)
+            .map_err(|err| error.pass(format!("Read file '{}' error: {}", path.display(), err)))?;
+        if img.empty() {
+            return Err(error.err(format!("Read file '{}' error: empty image", path.display())));
+        }
+        log::debug!("MatchPattern.read | file '{}' read successfully: {:?}", path.display(), img.size());
+        Ok(img)
+    }
+}
+///
+/// Patterns, searched on the frames
+enum Patterns {
+    /// Single pattern along with it's image
+    Single(Mat, Pattern),
+    /// All the patterns of the library
+    Library(PatternLibrary),
+}
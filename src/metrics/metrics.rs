@@ -0,0 +1,44 @@
+use std::{fmt::Display, time::Duration};
+use super::StageStats;
+///
+/// Collects the latency of each stage for every frame of the run
+/// 
+/// Stages are reported in the order they were recorded first
+#[derive(Debug, Clone, Default)]
+pub struct Metrics {
+    stages: Vec<(String, Vec<Duration>)>,
+}
+//
+//
+impl Metrics {
+    ///
+    /// Returns empty [Metrics]
+    pub fn new() -> Self {
+        Self { stages: vec![] }
+    }
+    ///
+    /// Records the latency of the `stage` for the current frame
+    pub fn record(&mut self, stage: &str, elapsed: Duration) {
+        match self.stages.iter_mut().find(|(name, _)| name == stage) {
+            Some((_, latencies)) => latencies.push(elapsed),
+            None => self.stages.push((stage.to_owned(), vec![elapsed])),
+        }
+    }
+    ///
+    /// Returns statistics of each recorded stage
+    pub fn stats(&self) -> Vec<StageStats> {
+        self.stages.iter()
+            .filter_map(|(name, latencies)| StageStats::new(name, latencies))
+            .collect()
+    }
+}
+//
+//
+impl Display for Metrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for stats in self.stats() {
+            writeln!(f, "{}", stats)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod metrics;
+mod stage_stats;
+pub use metrics::*;
+pub use stage_stats::*;
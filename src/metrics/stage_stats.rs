@@ -0,0 +1,96 @@
+use std::{fmt::Display, time::Duration};
+use serde::Serialize;
+///
+/// Latency statistics of the single stage over the run, milliseconds
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StageStats {
+    /// Name of the stage
+    pub name: String,
+    /// Number of the recorded latencies
+    pub count: usize,
+    /// Minimum latency
+    pub min: f64,
+    /// Mean latency
+    pub mean: f64,
+    /// 95th percentile of the latency
+    pub p95: f64,
+    /// Maximum latency
+    pub max: f64,
+}
+//
+//
+impl StageStats {
+    ///
+    /// Returns [StageStats] of the `latencies`, `None` if nothing recorded
+    pub fn new(name: impl Into<String>, latencies: &[Duration]) -> Option<Self> {
+        if latencies.is_empty() {
+            return None;
+        }
+        let mut latencies: Vec<f64> = latencies.iter().map(|latency| latency.as_secs_f64() * 1000.0).collect();
+        latencies.sort_by(f64::total_cmp);
+        let count = latencies.len();
+        let p95 = ((count as f64) * 0.95).ceil() as usize;
+        Some(Self {
+            name: name.into(),
+            count,
+            min: latencies[0],
+            mean: latencies.iter().sum::<f64>() / (count as f64),
+            p95: latencies[p95.clamp(1, count) - 1],
+            max: latencies[count - 1],
+        })
+    }
+}
+//
+//
+impl Display for StageStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:<24} count: {:>6}, min: {:>9.3} ms, mean: {:>9.3} ms, p95: {:>9.3} ms, max: {:>9.3} ms",
+            self.name, self.count, self.min, self.mean, self.p95, self.max,
+        )
+    }
+}
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::StageStats;
+    ///
+    /// Returns [StageStats] of the latencies 1..=`count` ms, recorded in the reverse order
+    fn stats(count: u64) -> StageStats {
+        let latencies: Vec<Duration> = (1..=count).rev().map(Duration::from_millis).collect();
+        StageStats::new("Stage", &latencies).unwrap()
+    }
+    #[test]
+    fn empty() {
+        assert_eq!(StageStats::new("Stage", &[]), None);
+    }
+    #[test]
+    fn single() {
+        let stats = stats(1);
+        assert_eq!(stats.count, 1);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.mean, 1.0);
+        assert_eq!(stats.p95, 1.0);
+        assert_eq!(stats.max, 1.0);
+    }
+    #[test]
+    fn p95_of_20() {
+        let stats = stats(20);
+        assert_eq!(stats.count, 20);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.mean, 10.5);
+        // 19th of 20 by the nearest rank, the maximum is excluded
+        assert_eq!(stats.p95, 19.0);
+        assert_eq!(stats.max, 20.0);
+    }
+    #[test]
+    fn p95_of_21() {
+        let stats = stats(21);
+        assert_eq!(stats.count, 21);
+        assert_eq!(stats.mean, 11.0);
+        // 20th of 21 by the nearest rank
+        assert_eq!(stats.p95, 20.0);
+        assert_eq!(stats.max, 21.0);
+    }
+}
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod mosaic;
+mod tile;
+pub use mosaic::*;
+pub use tile::*;
@@ -0,0 +1,108 @@
+use opencv::{core::{self, Mat, Point, Rect, Scalar, Size, Vector}, imgproc, prelude::*};
+use sal_core::error::Error;
+use crate::stage::PixelFormat;
+use super::Tile;
+///
+/// Renders the outputs of all stages into the single image
+/// 
+/// Each [Tile] is converted to 8-bit BGR (masks become gray 3-channel),
+/// resized to the common `height` keeping the aspect ratio and labelled with it's name and timing,
+/// tiles are placed left to right, `columns` in the row, rows are padded with black to the same width
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mosaic {
+    height: i32,
+    columns: usize,
+}
+//
+//
+impl Mosaic {
+    ///
+    /// Scale of the label font relative to the tile height
+    const FONT_SCALE: f64 = 1.0 / 480.0;
+    ///
+    /// Returns [Mosaic] new instance
+    /// - `height` - height of the each tile, pixels
+    /// - `columns` - number of the tiles in the row
+    pub fn new(height: i32, columns: usize) -> Self {
+        Self {
+            height: height.max(1),
+            columns: columns.max(1),
+        }
+    }
+    ///
+    /// Returns the mosaic of the `tiles` in the order
+    pub fn render(&self, tiles: &[Tile]) -> Result<Mat, Error> {
+        let error = Error::new("Mosaic", "render");
+        if tiles.is_empty() {
+            return Err(error.err("Nothing to render, tiles are empty".to_owned()));
+        }
+        let mut rows = vec![];
+        for chunk in tiles.chunks(self.columns) {
+            let mut row = Vector::<Mat>::new();
+            for tile in chunk {
+                row.push(self.tile(tile)?);
+            }
+            let mut dst = Mat::default();
+            core::hconcat(&row, &mut dst)
+                .map_err(|err| error.pass(err.to_string()))?;
+            rows.push(dst);
+        }
+        let width = rows.iter().map(|row| row.cols()).max().unwrap_or(0);
+        let mut padded = Vector::<Mat>::new();
+        for row in rows {
+            let mut dst = Mat::default();
+            core::copy_make_border(&row, &mut dst, 0, 0, 0, width - row.cols(), core::BORDER_CONSTANT, Scalar::all(0.0))
+                .map_err(|err| error.pass(err.to_string()))?;
+            padded.push(dst);
+        }
+        let mut dst = Mat::default();
+        core::vconcat(&padded, &mut dst)
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(dst)
+    }
+    ///
+    /// Returns the `tile` with the image converted to 8-bit BGR and resized to the tile height,
+    /// so the tile takes the memory of the mosaic cell only, rendered same as the original one
+    pub fn shrink(&self, tile: &Tile) -> Result<Tile, Error> {
+        let mat = if tile.mat.empty() { Mat::default() } else { self.resize(&tile.mat)? };
+        Ok(Tile::new(tile.name.clone(), mat, tile.elapsed))
+    }
+    ///
+    /// Returns the not empty `img` converted to 8-bit BGR and resized to the tile height, keeping the aspect ratio
+    fn resize(&self, img: &Mat) -> Result<Mat, Error> {
+        let error = Error::new("Mosaic", "resize");
+        let img = PixelFormat::of(img)?.to_bgr8(img)?;
+        let width = ((img.cols() as f64) * (self.height as f64) / (img.rows() as f64)).round().max(1.0) as i32;
+        if img.rows() == self.height && img.cols() == width {
+            return Ok(img);
+        }
+        let interpolation = if self.height < img.rows() { imgproc::INTER_AREA } else { imgproc::INTER_LINEAR };
+        let mut dst = Mat::default();
+        imgproc::resize(&img, &mut dst, Size::new(width, self.height), 0.0, 0.0, interpolation)
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(dst)
+    }
+    ///
+    /// Returns the `tile` image, converted to 8-bit BGR, resized and labelled
+    fn tile(&self, tile: &Tile) -> Result<Mat, Error> {
+        let error = Error::new("Mosaic", "tile");
+        let mut dst = if tile.mat.empty() {
+            Mat::new_rows_cols_with_default(self.height, self.height, core::CV_8UC3, Scalar::all(0.0))
+                .map_err(|err| error.pass(err.to_string()))?
+        } else {
+            self.resize(&tile.mat)?
+        };
+        let scale = (self.height as f64 * Self::FONT_SCALE).max(0.3);
+        let thickness = (scale * 1.5).round().max(1.0) as i32;
+        let label = tile.label();
+        let mut baseline = 0;
+        let size = imgproc::get_text_size(&label, imgproc::FONT_HERSHEY_SIMPLEX, scale, thickness, &mut baseline)
+            .map_err(|err| error.pass(err.to_string()))?;
+        let banner = Rect::new(0, 0, (size.width + 8).min(dst.cols()), (size.height + baseline + 8).min(dst.rows()));
+        imgproc::rectangle(&mut dst, banner, Scalar::all(0.0), imgproc::FILLED, imgproc::LINE_8, 0)
+            .map_err(|err| error.pass(err.to_string()))?;
+        imgproc::put_text(&mut dst, &label, Point::new(4, size.height + 4), imgproc::FONT_HERSHEY_SIMPLEX, scale, Scalar::new(0.0, 255.0, 0.0, 0.0), thickness, imgproc::LINE_AA, false)
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(dst)
+    }
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+use opencv::core::Mat;
+///
+/// Single image of the [Mosaic](super::Mosaic)
+#[derive(Debug, Clone)]
+pub struct Tile {
+    /// Name of the output, the image belongs to, the label of the tile
+    pub name: String,
+    /// Image of any format, supported by the [PixelFormat](crate::stage::PixelFormat)
+    pub mat: Mat,
+    /// Time spent to produce the image, shown along with the name if present
+    pub elapsed: Option<Duration>,
+}
+//
+//
+impl Tile {
+    ///
+    /// Returns [Tile] new instance
+    pub fn new(name: impl Into<String>, mat: Mat, elapsed: Option<Duration>) -> Self {
+        Self {
+            name: name.into(),
+            mat,
+            elapsed,
+        }
+    }
+    ///
+    /// Returns the label of the tile, the name and the time spent in milliseconds
+    pub fn label(&self) -> String {
+        match self.elapsed {
+            Some(elapsed) => format!("{} {:.1} ms", self.name, elapsed.as_secs_f64() * 1000.0),
+            None => self.name.clone(),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use opencv::{core::{self, DMatch, Mat, Ptr, Vector}, features2d, flann, prelude::*};
+use sal_core::error::Error;
+use crate::conf::{MatcherAlgorithm, MatcherConf};
+///
+/// Matches the query descriptors against the train descriptors
+/// 
+/// The nearest neighbours search is chosen by the descriptors type:
+/// - binary (`CV_8U`) - brute-force with the Hamming norm, or FLANN with the LSH index
+/// - float (`CV_32F`) - brute-force with the L2 norm, or FLANN with the KD-tree index
+/// 
+/// The matches are filtered by the Lowe's ratio test and the cross-check if enabled
+pub struct DescriptorMatcher {
+    conf: MatcherConf,
+}
+//
+//
+impl DescriptorMatcher {
+    ///
+    /// Number of the LSH hash tables
+    const LSH_TABLES: i32 = 6;
+    ///
+    /// Length of the LSH hash key, bits
+    const LSH_KEY_SIZE: i32 = 12;
+    ///
+    /// Number of the neighbouring buckets the LSH searches in
+    const LSH_MULTI_PROBE: i32 = 1;
+    ///
+    /// Number of the randomized KD-trees
+    const KD_TREES: i32 = 4;
+    ///
+    /// Returns [DescriptorMatcher] new instance
+    pub fn new(conf: MatcherConf) -> Self {
+        Self { conf }
+    }
+    ///
+    /// Returns the good matches of the `query` descriptors against the `train` descriptors,
+    /// `query_idx` refers to the `query` rows, `train_idx` refers to the `train` rows
+    /// - `binary_norm` - norm of the binary descriptors for the brute-force search, `NORM_HAMMING` or `NORM_HAMMING2`
+    pub fn eval(&self, query: &Mat, train: &Mat, binary_norm: i32) -> Result<Vector<DMatch>, Error> {
+        let dbg = "DescriptorMatcher";
+        let error = Error::new(dbg, "eval");
+        if query.empty() || train.empty() {
+            return Ok(Vector::default());
+        }
+        if query.typ() != train.typ() {
+            return Err(error.err(format!("descriptors type mismatch: {} / {}", query.typ(), train.typ())));
+        }
+        let matcher = self.matcher(query, binary_norm)?;
+        let k = if self.conf.ratio.is_some() { 2 } else { 1 };
+        let mut knn: Vector<Vector<DMatch>> = Vector::default();
+        matcher.knn_train_match(query, train, &mut knn, k, &core::no_array(), false)
+            .map_err(|err| error.pass(format!("knn_match error: {}", err)))?;
+        log::trace!("{dbg}.eval | matches: {:?}", knn);
+        // Best matches of the train descriptors, the cross-check compares against
+        let reverse = if self.conf.cross_check {
+            let mut reverse: Vector<DMatch> = Vector::default();
+            matcher.train_match(train, query, &mut reverse, &core::no_array())
+                .map_err(|err| error.pass(format!("match error: {}", err)))?;
+            let mut best = vec![None; train.rows() as usize];
+            for m in &reverse {
+                if let Some(slot) = best.get_mut(m.query_idx as usize) {
+                    *slot = Some(m.train_idx);
+                }
+            }
+            Some(best)
+        } else {
+            None
+        };
+        let mut good_matches = Vector::default();
+        for mm in knn {
+            let Ok(m0) = mm.get(0) else {
+                continue;
+            };
+            if let Some(ratio) = self.conf.ratio {
+                let Ok(m1) = mm.get(1) else {
+                    continue;
+                };
+                if m0.distance >= ratio * m1.distance {
+                    continue;
+                }
+            }
+            if let Some(reverse) = &reverse {
+                if reverse.get(m0.train_idx as usize).copied().flatten() != Some(m0.query_idx) {
+                    continue;
+                }
+            }
+            good_matches.push(m0);
+        }
+        log::trace!("{dbg}.eval | good matches: {} of {}", good_matches.len(), query.rows());
+        Ok(good_matches)
+    }
+    ///
+    /// Returns the matcher suitable for the type of the `descriptors`
+    fn matcher(&self, descriptors: &Mat, binary_norm: i32) -> Result<Ptr<features2d::DescriptorMatcher>, Error> {
+        let error = Error::new("DescriptorMatcher", "matcher");
+        let binary = match descriptors.depth() {
+            core::CV_8U => true,
+            core::CV_32F => false,
+            depth => return Err(error.err(format!("descriptors depth expected CV_8U or CV_32F, but got {}", depth))),
+        };
+        match self.conf.algorithm {
+            MatcherAlgorithm::BruteForce => {
+                let norm = if binary { binary_norm } else { core::NORM_L2 };
+                features2d::BFMatcher::create(norm, false)
+                    .map(Ptr::from)
+                    .map_err(|err| error.pass(format!("BFMatcher create error: {}", err)))
+            }
+            MatcherAlgorithm::Flann => {
+                let index: Ptr<flann::IndexParams> = if binary {
+                    flann::LshIndexParams::new(Self::LSH_TABLES, Self::LSH_KEY_SIZE, Self::LSH_MULTI_PROBE)
+                        .map(|params| Ptr::new(params).into())
+                } else {
+                    flann::KDTreeIndexParams::new(Self::KD_TREES)
+                        .map(|params| Ptr::new(params).into())
+                }.map_err(|err| error.pass(format!("FLANN index params error: {}", err)))?;
+                let search = flann::SearchParams::new_def()
+                    .map(|params| Ptr::new(params).into())
+                    .map_err(|err| error.pass(format!("FLANN search params error: {}", err)))?;
+                features2d::FlannBasedMatcher::new(&index, &search)
+                    .map(|matcher| Ptr::new(matcher).into())
+                    .map_err(|err| error.pass(format!("FlannBasedMatcher create error: {}", err)))
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use opencv::{core::Mat, imgcodecs, prelude::*};
+    use crate::conf::{DetectorConf, MatcherConf};
+    use super::super::FeatureDetector;
+    use super::DescriptorMatcher;
+    ///
+    /// Returns the descriptors of the reference pattern image along with the norm of the detector
+    fn descriptors() -> (Mat, i32) {
+        let img = imgcodecs::imread("./assets/patterns/pattern1.png", imgcodecs::IMREAD_COLOR).unwrap();
+        let mut detector = FeatureDetector::new(&DetectorConf::default()).unwrap();
+        let (_, descriptors) = detector.detect(&img).unwrap();
+        assert!(!descriptors.empty());
+        (descriptors, detector.binary_norm())
+    }
+    #[test]
+    fn itself() {
+        let (descriptors, norm) = descriptors();
+        let matcher = DescriptorMatcher::new(MatcherConf { cross_check: true, ..MatcherConf::default() });
+        let matches = matcher.eval(&descriptors, &descriptors, norm).unwrap();
+        assert!(!matches.is_empty());
+        // Each descriptor is the nearest to itself
+        let same = matches.iter().filter(|m| m.query_idx == m.train_idx).count();
+        assert_eq!(same, matches.len());
+    }
+    #[test]
+    fn empty() {
+        let (descriptors, norm) = descriptors();
+        let matcher = DescriptorMatcher::new(MatcherConf::default());
+        assert!(matcher.eval(&descriptors, &Mat::default(), norm).unwrap().is_empty());
+        assert!(matcher.eval(&Mat::default(), &descriptors, norm).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+use opencv::{core::{self, KeyPoint, Mat, Ptr, Vector}, features2d, prelude::*};
+use sal_core::error::Error;
+use crate::conf::{AkazeConf, AkazeDescriptorType, AkazeDiffusivity, BriskConf, DetectorConf, OrbConf, OrbScoreType, SiftConf};
+///
+/// Detects the keypoints and extracts their descriptors,
+/// one of ORB, AKAZE, BRISK or SIFT, selected by the [DetectorConf]
+pub struct FeatureDetector {
+    detector: Ptr<features2d::Feature2D>,
+    binary_norm: i32,
+}
+//
+//
+impl FeatureDetector {
+    ///
+    /// Returns [FeatureDetector] new instance
+    pub fn new(conf: &DetectorConf) -> Result<Self, Error> {
+        let error = Error::new("FeatureDetector", "new");
+        let binary_norm = match conf {
+            // Each element of the ORB descriptor is 2 bits if the `wta-k` is 3 or 4
+            DetectorConf::Orb(conf) if conf.wta_k > 2 => core::NORM_HAMMING2,
+            _ => core::NORM_HAMMING,
+        };
+        let detector: opencv::Result<Ptr<features2d::Feature2D>> = match conf {
+            DetectorConf::Orb(conf) => Self::orb(conf),
+            DetectorConf::Akaze(conf) => Self::akaze(conf),
+            DetectorConf::Brisk(conf) => Self::brisk(conf),
+            DetectorConf::Sift(conf) => Self::sift(conf),
+        };
+        Ok(Self {
+            detector: detector.map_err(|err| error.pass(format!("creating detector {:?} error: {}", conf, err)))?,
+            binary_norm,
+        })
+    }
+    ///
+    /// Returns ORB features detector configured by the `conf`
+    fn orb(conf: &OrbConf) -> opencv::Result<Ptr<features2d::Feature2D>> {
+        let score_type = match conf.score_type {
+            OrbScoreType::Harris => features2d::ORB_ScoreType::HARRIS_SCORE,
+            OrbScoreType::Fast => features2d::ORB_ScoreType::FAST_SCORE,
+        };
+        features2d::ORB::create(
+            conf.nfeatures,
+            conf.scale_factor,
+            conf.nlevels,
+            conf.edge_threshold,
+            conf.first_level,
+            conf.wta_k,
+            score_type,
+            conf.patch_size,
+            conf.fast_threshold,
+        ).map(Ptr::from)
+    }
+    ///
+    /// Returns AKAZE features detector configured by the `conf`
+    fn akaze(conf: &AkazeConf) -> opencv::Result<Ptr<features2d::Feature2D>> {
+        let descriptor_type = match conf.descriptor_type {
+            AkazeDescriptorType::Kaze => features2d::AKAZE_DescriptorType::DESCRIPTOR_KAZE,
+            AkazeDescriptorType::KazeUpright => features2d::AKAZE_DescriptorType::DESCRIPTOR_KAZE_UPRIGHT,
+            AkazeDescriptorType::Mldb => features2d::AKAZE_DescriptorType::DESCRIPTOR_MLDB,
+            AkazeDescriptorType::MldbUpright => features2d::AKAZE_DescriptorType::DESCRIPTOR_MLDB_UPRIGHT,
+        };
+        let diffusivity = match conf.diffusivity {
+            AkazeDiffusivity::PmG1 => features2d::KAZE_DiffusivityType::DIFF_PM_G1,
+            AkazeDiffusivity::PmG2 => features2d::KAZE_DiffusivityType::DIFF_PM_G2,
+            AkazeDiffusivity::Weickert => features2d::KAZE_DiffusivityType::DIFF_WEICKERT,
+            AkazeDiffusivity::Charbonnier => features2d::KAZE_DiffusivityType::DIFF_CHARBONNIER,
+        };
+        features2d::AKAZE::create(
+            descriptor_type,
+            conf.descriptor_size,
+            conf.descriptor_channels,
+            conf.threshold,
+            conf.n_octaves,
+            conf.n_octave_layers,
+            diffusivity,
+            conf.max_points,
+        ).map(Ptr::from)
+    }
+    ///
+    /// Returns BRISK features detector configured by the `conf`
+    fn brisk(conf: &BriskConf) -> opencv::Result<Ptr<features2d::Feature2D>> {
+        features2d::BRISK::create(
+            conf.threshold,
+            conf.octaves,
+            conf.pattern_scale,
+        ).map(Ptr::from)
+    }
+    ///
+    /// Returns SIFT features detector configured by the `conf`
+    fn sift(conf: &SiftConf) -> opencv::Result<Ptr<features2d::Feature2D>> {
+        features2d::SIFT::create(
+            conf.nfeatures,
+            conf.n_octave_layers,
+            conf.contrast_threshold,
+            conf.edge_threshold,
+            conf.sigma,
+            conf.enable_precise_upscale,
+        ).map(Ptr::from)
+    }
+    ///
+    /// Returns the norm of the binary descriptors for the brute-force matching,
+    /// not used for the float descriptors
+    pub fn binary_norm(&self) -> i32 {
+        self.binary_norm
+    }
+    ///
+    /// Returns the keypoints detected on the `img` and their descriptors
+    pub fn detect(&mut self, img: &Mat) -> Result<(Vector<KeyPoint>, Mat), Error> {
+        let error = Error::new("FeatureDetector", "detect");
+        let mut keypoints = Vector::default();
+        let mut descriptors = Mat::default();
+        self.detector.detect_and_compute(img, &core::no_array(), &mut keypoints, &mut descriptors, false)
+            .map_err(|err| error.pass(format!("detect_and_compute error: {}", err)))?;
+        Ok((keypoints, descriptors))
+    }
+}
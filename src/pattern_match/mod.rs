@@ -0,0 +1,17 @@
+mod descriptor_matcher;
+mod feature_detector;
+mod pattern;
+mod pattern_detection;
+mod pattern_library;
+mod pattern_location;
+#[allow(clippy::module_inception)]
+mod pattern_match;
+mod pattern_matcher;
+pub use descriptor_matcher::*;
+pub use feature_detector::*;
+pub use pattern::*;
+pub use pattern_detection::*;
+pub use pattern_library::*;
+pub use pattern_location::*;
+pub use pattern_match::*;
+pub use pattern_matcher::*;
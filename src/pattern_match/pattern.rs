@@ -0,0 +1,15 @@
+use opencv::core::{KeyPoint, Mat, Size, Vector};
+///
+/// Pattern with the precomputed keypoints and descriptors,
+/// returned by the [PatternMatcher::pattern](super::PatternMatcher::pattern)
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    /// Name of the pattern, the file stem for the patterns loaded from the files
+    pub name: String,
+    /// Size of the pattern image
+    pub size: Size,
+    /// Keypoints detected on the pattern
+    pub keypoints: Vector<KeyPoint>,
+    /// Descriptors of the `keypoints`
+    pub descriptors: Mat,
+}
@@ -0,0 +1,35 @@
+use opencv::{core::{Mat, Point, Scalar, Vector}, imgproc};
+use sal_core::error::Error;
+use super::PatternLocation;
+///
+/// Pattern of the [PatternLibrary](super::PatternLibrary), found on the scene
+#[derive(Debug, Clone)]
+pub struct PatternDetection {
+    /// Name of the pattern
+    pub name: String,
+    /// Location of the pattern on the scene
+    pub location: PatternLocation,
+    /// Share of the pattern keypoints, confirmed by the homography, [0, 1]
+    pub confidence: f64,
+}
+//
+//
+impl PatternDetection {
+    ///
+    /// Outlines the pattern on the `img` of the scene and labels it with the name and confidence
+    pub fn draw(&self, img: &mut Mat) -> Result<(), Error> {
+        let error = Error::new("PatternDetection", "draw");
+        let color = Scalar::new(0.0, 255.0, 0.0, 0.0);
+        let quad: Vector<Point> = self.location.corners.iter()
+            .map(|corner| Point::new(corner.x.round() as i32, corner.y.round() as i32))
+            .collect();
+        imgproc::polylines(img, &Vector::<Vector<Point>>::from_iter([quad.clone()]), true, color, 3, imgproc::LINE_AA, 0)
+            .map_err(|err| error.pass(err.to_string()))?;
+        // Label above the top-most corner
+        let origin = quad.iter().min_by_key(|point| point.y).unwrap_or_default();
+        let label = format!("{} {:.2}", self.name, self.confidence);
+        imgproc::put_text(img, &label, Point::new(origin.x, (origin.y - 8).max(16)), imgproc::FONT_HERSHEY_SIMPLEX, 0.8, color, 2, imgproc::LINE_AA, false)
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(())
+    }
+}
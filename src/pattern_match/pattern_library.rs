@@ -0,0 +1,75 @@
+use std::path::Path;
+use opencv::{imgcodecs, prelude::*};
+use sal_core::error::Error;
+use crate::frame_source::ImageSequence;
+use super::{Pattern, PatternMatcher};
+///
+/// Set of the [Pattern]'s with the precomputed descriptors,
+/// matched against the scene all at once by the [PatternMatcher::eval_library]
+/// 
+/// Descriptors are computed by the detector of the `matcher` the library loaded with,
+/// so the library must be matched by the same [PatternMatcher]
+#[derive(Debug, Clone, Default)]
+pub struct PatternLibrary {
+    patterns: Vec<Pattern>,
+}
+//
+//
+impl PatternLibrary {
+    ///
+    /// Returns [PatternLibrary] of all the images in the `dir`, ordered naturally,
+    /// files, which can't be read as the image, are skipped
+    pub fn load(dir: impl AsRef<Path>, matcher: &mut PatternMatcher) -> Result<Self, Error> {
+        let dir = dir.as_ref();
+        let dbg = "PatternLibrary";
+        let error = Error::new(dbg, "load");
+        let mut patterns = vec![];
+        for item in ImageSequence::new(dir, 0, None, 1)? {
+            let path = item.path;
+            let img = imgcodecs::imread(&path.to_string_lossy(), imgcodecs::IMREAD_COLOR)
+                .map_err(|err| error.pass(format!("Read file '{}' error: {}", path.display(), err)))?;
+            if img.empty() {
+                log::warn!("{dbg}.load | file '{}' skipped: not an image", path.display());
+                continue;
+            }
+            let name = path.file_stem().map_or_else(|| path.to_string_lossy(), |stem| stem.to_string_lossy()).into_owned();
+            let pattern = matcher.pattern(name, &img)?;
+            log::debug!("{dbg}.load | pattern '{}': {} keypoints", pattern.name, pattern.keypoints.len());
+            patterns.push(pattern);
+        }
+        if patterns.is_empty() {
+            return Err(error.err(format!("No pattern images in '{}'", dir.display())));
+        }
+        log::info!("{dbg}.load | {} patterns loaded from '{}'", patterns.len(), dir.display());
+        Ok(Self { patterns })
+    }
+    ///
+    /// Returns the patterns of the library
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+}
+#[cfg(test)]
+mod tests {
+    use opencv::{core::{self, Mat, Scalar}, imgcodecs, prelude::*};
+    use crate::conf::PatternMatchConf;
+    use super::super::PatternMatcher;
+    use super::PatternLibrary;
+    #[test]
+    fn load() {
+        let mut matcher = PatternMatcher::new(PatternMatchConf::default()).unwrap();
+        let library = PatternLibrary::load("./assets/patterns/", &mut matcher).unwrap();
+        let names: Vec<_> = library.patterns().iter().map(|pattern| pattern.name.as_str()).collect();
+        assert_eq!(names, ["p1", "pattern1"]);
+    }
+    #[test]
+    fn eval() {
+        let mut matcher = PatternMatcher::new(PatternMatchConf::default()).unwrap();
+        let library = PatternLibrary::load("./assets/patterns/", &mut matcher).unwrap();
+        let scene = imgcodecs::imread("./assets/patterns/pattern1.png", imgcodecs::IMREAD_COLOR).unwrap();
+        let detections = matcher.eval_library(&library, &scene).unwrap();
+        assert!(detections.iter().any(|detection| detection.name == "pattern1"));
+        let blank = Mat::new_rows_cols_with_default(scene.rows(), scene.cols(), core::CV_8UC3, Scalar::all(255.0)).unwrap();
+        assert!(matcher.eval_library(&library, &blank).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+use opencv::core::{Mat, Point2f, Vector};
+///
+/// Location of the pattern on the scene, estimated by the homography from the good matches
+#[derive(Debug, Clone)]
+pub struct PatternLocation {
+    /// 3x3 perspective transformation from the pattern to the scene
+    pub homography: Mat,
+    /// Pattern corners projected onto the scene,
+    /// top-left, top-right, bottom-right, bottom-left of the pattern
+    pub corners: Vector<Point2f>,
+    /// Number of the good matches, consistent with the homography
+    pub inliers: usize,
+    /// Mean distance between the projected pattern keypoints and the matched scene keypoints of the inliers, pixels
+    pub error: f64,
+}
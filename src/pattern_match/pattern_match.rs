@@ -0,0 +1,61 @@
+use opencv::{core::{self, DMatch, KeyPoint, Mat, Point, Scalar, Vector}, features2d, imgproc, prelude::*};
+use sal_core::error::Error;
+use super::PatternLocation;
+///
+/// Result of the pattern matching, returned by the [PatternMatcher](super::PatternMatcher)
+#[derive(Debug, Clone)]
+pub struct PatternMatch {
+    /// Keypoints detected on the pattern
+    pub pattern_keypoints: Vector<KeyPoint>,
+    /// Keypoints detected on the scene
+    pub scene_keypoints: Vector<KeyPoint>,
+    /// Matches passed the ratio test and the cross-check if enabled,
+    /// `query_idx` refers to the `pattern_keypoints`, `train_idx` refers to the `scene_keypoints`
+    pub good_matches: Vector<DMatch>,
+    /// Share of the pattern keypoints, having the good match in the scene, [0, 1]
+    pub score: f64,
+    /// Location of the pattern on the scene, `None` if the homography can't be estimated
+    pub location: Option<PatternLocation>,
+    /// Share of the pattern keypoints, confirmed by the homography, [0, 1], 0 if not located
+    pub confidence: f64,
+    /// Location satisfies the inliers and the reprojection error thresholds
+    pub found: bool,
+}
+//
+//
+impl PatternMatch {
+    ///
+    /// Returns the `pattern` and the `scene` side by side with the good matches drawn,
+    /// the projected pattern outlined on the scene, green if found, red otherwise
+    pub fn draw(&self, pattern: &Mat, scene: &Mat) -> Result<Mat, Error> {
+        let error = Error::new("PatternMatch", "draw");
+        let mut out = Mat::default();
+        features2d::draw_matches(
+            pattern,
+            &self.pattern_keypoints,
+            scene,
+            &self.scene_keypoints,
+            &self.good_matches,
+            &mut out,
+            Scalar::new(0f64, 255f64, 0f64, 0f64),
+            Scalar::new(0f64, 255f64, 0f64, 0f64),
+            &core::Vector::default(),
+            features2d::DrawMatchesFlags::NOT_DRAW_SINGLE_POINTS,
+        ).map_err(|err| error.pass(err.to_string()))?;
+        if let Some(location) = &self.location {
+            // The scene is drawn to the right of the pattern
+            let offset = pattern.cols() as f32;
+            let quad: Vector<Point> = location.corners.iter()
+                .map(|corner| Point::new((corner.x + offset).round() as i32, corner.y.round() as i32))
+                .collect();
+            let color = if self.found {
+                Scalar::new(0.0, 255.0, 0.0, 0.0)
+            } else {
+                Scalar::new(0.0, 0.0, 255.0, 0.0)
+            };
+            imgproc::polylines(&mut out, &Vector::<Vector<Point>>::from_iter([quad]), true, color, 3, imgproc::LINE_AA, 0)
+                .map_err(|err| error.pass(err.to_string()))?;
+        }
+        Ok(out)
+    }
+}
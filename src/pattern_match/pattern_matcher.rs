@@ -0,0 +1,218 @@
+use opencv::{calib3d, core::{self, DMatch, KeyPoint, Mat, Point2f, Vector}, prelude::*};
+use sal_core::error::Error;
+use crate::conf::{HomographyConf, HomographyMethod, PatternMatchConf};
+use super::{DescriptorMatcher, FeatureDetector, Pattern, PatternDetection, PatternLibrary, PatternLocation, PatternMatch};
+///
+/// Matches the pattern image against the scene image using [FeatureDetector] and [DescriptorMatcher]
+/// and locates the pattern on the scene by the homography estimated from the good matches
+/// 
+/// The pattern is found if the homography has at least `min-inliers` inliers
+/// and their mean reprojection error doesn't exceed `max-error`
+/// 
+/// ```ignore
+/// let mut matcher = PatternMatcher::new(PatternMatchConf::default())?;
+/// let result = matcher.eval(&pattern, &scene)?;
+/// log::info!("found: {}, score: {}, good matches: {}", result.found, result.score, result.good_matches.len());
+/// let img = result.draw(&pattern, &scene)?;
+/// ```
+/// 
+/// Several patterns are matched at once using the [PatternLibrary], the scene features are detected only once:
+/// ```ignore
+/// let library = PatternLibrary::load("./assets/patterns/", &mut matcher)?;
+/// for detection in matcher.eval_library(&library, &scene)? {
+///     log::info!("{}: {:.3} at {:?}", detection.name, detection.confidence, detection.location.corners);
+/// }
+/// ```
+pub struct PatternMatcher {
+    conf: PatternMatchConf,
+    detector: FeatureDetector,
+    matcher: DescriptorMatcher,
+}
+//
+//
+impl PatternMatcher {
+    ///
+    /// Returns [PatternMatcher] new instance
+    pub fn new(conf: PatternMatchConf) -> Result<Self, Error> {
+        Ok(Self {
+            detector: FeatureDetector::new(&conf.detector)?,
+            matcher: DescriptorMatcher::new(conf.matcher.clone()),
+            conf,
+        })
+    }
+    ///
+    /// Returns the [Pattern] with the keypoints and descriptors precomputed on the `img`
+    pub fn pattern(&mut self, name: impl Into<String>, img: &Mat) -> Result<Pattern, Error> {
+        let error = Error::new("PatternMatcher", "pattern");
+        let (keypoints, descriptors) = self.detector.detect(img)?;
+        Ok(Pattern {
+            name: name.into(),
+            size: img.size().map_err(|err| error.pass(err.to_string()))?,
+            keypoints,
+            descriptors,
+        })
+    }
+    ///
+    /// Returns the result of matching the `pattern` against the `scene`
+    pub fn eval(&mut self, pattern: &Mat, scene: &Mat) -> Result<PatternMatch, Error> {
+        let pattern = self.pattern("pattern", pattern)?;
+        self.eval_pattern(&pattern, scene)
+    }
+    ///
+    /// Returns the result of matching the precomputed `pattern` against the `scene`
+    pub fn eval_pattern(&mut self, pattern: &Pattern, scene: &Mat) -> Result<PatternMatch, Error> {
+        let (scene_keypoints, scene_descriptors) = self.detector.detect(scene)?;
+        self.match_pattern(pattern, scene_keypoints, &scene_descriptors)
+    }
+    ///
+    /// Returns the patterns of the `library` found on the `scene`, in the order of the library
+    pub fn eval_library(&mut self, library: &PatternLibrary, scene: &Mat) -> Result<Vec<PatternDetection>, Error> {
+        let (scene_keypoints, scene_descriptors) = self.detector.detect(scene)?;
+        let mut detections = vec![];
+        for pattern in library.patterns() {
+            let result = self.match_pattern(pattern, scene_keypoints.clone(), &scene_descriptors)?;
+            log::debug!("PatternMatcher.eval_library | '{}' found: {}, confidence: {:.3}", pattern.name, result.found, result.confidence);
+            if let (true, Some(location)) = (result.found, result.location) {
+                detections.push(PatternDetection {
+                    name: pattern.name.clone(),
+                    location,
+                    confidence: result.confidence,
+                });
+            }
+        }
+        Ok(detections)
+    }
+    ///
+    /// Returns the result of matching the precomputed `pattern` against the scene features
+    fn match_pattern(&self, pattern: &Pattern, scene_keypoints: Vector<KeyPoint>, scene_descriptors: &Mat) -> Result<PatternMatch, Error> {
+        let dbg = "PatternMatcher";
+        let good_matches = self.matcher.eval(&pattern.descriptors, scene_descriptors, self.detector.binary_norm())?;
+        let pattern_keypoints = pattern.keypoints.clone();
+        let score = if pattern_keypoints.is_empty() {
+            0.0
+        } else {
+            good_matches.len() as f64 / pattern_keypoints.len() as f64
+        };
+        log::debug!("{dbg}.match_pattern | '{}' keypoints: {} / {}, good matches: {}, score: {:.3}", pattern.name, pattern_keypoints.len(), scene_keypoints.len(), good_matches.len(), score);
+        let location = Self::locate(&self.conf.homography, pattern.size, &pattern_keypoints, &scene_keypoints, &good_matches)?;
+        let found = location.as_ref().is_some_and(|location| {
+            location.inliers >= self.conf.homography.min_inliers && location.error <= self.conf.homography.max_error
+        });
+        let confidence = match &location {
+            Some(location) => {
+                log::debug!("{dbg}.match_pattern | '{}' found: {}, inliers: {}, error: {:.3}, corners: {:?}", pattern.name, found, location.inliers, location.error, location.corners);
+                location.inliers as f64 / pattern_keypoints.len() as f64
+            }
+            None => {
+                log::debug!("{dbg}.match_pattern | '{}' found: false, homography not estimated", pattern.name);
+                0.0
+            }
+        };
+        Ok(PatternMatch {
+            pattern_keypoints,
+            scene_keypoints,
+            good_matches,
+            score,
+            location,
+            confidence,
+            found,
+        })
+    }
+    ///
+    /// Returns the pattern location estimated from the `good_matches`,
+    /// `None` if there are less than 4 matches or the homography is degenerate
+    fn locate(
+        conf: &HomographyConf,
+        size: core::Size,
+        pattern_keypoints: &Vector<KeyPoint>,
+        scene_keypoints: &Vector<KeyPoint>,
+        good_matches: &Vector<DMatch>,
+    ) -> Result<Option<PatternLocation>, Error> {
+        let error = Error::new("PatternMatcher", "locate");
+        if good_matches.len() < 4 {
+            return Ok(None);
+        }
+        let mut src: Vector<Point2f> = Vector::with_capacity(good_matches.len());
+        let mut dst: Vector<Point2f> = Vector::with_capacity(good_matches.len());
+        for m in good_matches {
+            let pattern_point = pattern_keypoints.get(m.query_idx as usize).map_err(|err| error.pass(err.to_string()))?;
+            let scene_point = scene_keypoints.get(m.train_idx as usize).map_err(|err| error.pass(err.to_string()))?;
+            src.push(pattern_point.pt());
+            dst.push(scene_point.pt());
+        }
+        let method = match conf.method {
+            HomographyMethod::Ransac => calib3d::RANSAC,
+            HomographyMethod::Usac => calib3d::USAC_DEFAULT,
+            HomographyMethod::UsacMagsac => calib3d::USAC_MAGSAC,
+            HomographyMethod::UsacAccurate => calib3d::USAC_ACCURATE,
+        };
+        let mut mask = Mat::default();
+        let homography = calib3d::find_homography_ext(&src, &dst, method, conf.reprojection_threshold, &mut mask, conf.max_iters, conf.confidence)
+            .map_err(|err| error.pass(format!("find_homography error: {}", err)))?;
+        if homography.empty() {
+            return Ok(None);
+        }
+        let mut projected: Vector<Point2f> = Vector::default();
+        core::perspective_transform(&src, &mut projected, &homography)
+            .map_err(|err| error.pass(err.to_string()))?;
+        let mut inliers = 0;
+        let mut distance = 0.0;
+        for (i, (projected, dst)) in projected.iter().zip(dst.iter()).enumerate() {
+            if *mask.at::<u8>(i as i32).map_err(|err| error.pass(err.to_string()))? != 0 {
+                inliers += 1;
+                distance += ((projected.x - dst.x) as f64).hypot((projected.y - dst.y) as f64);
+            }
+        }
+        if inliers == 0 {
+            return Ok(None);
+        }
+        let (width, height) = (size.width as f32, size.height as f32);
+        let corners = Vector::from_slice(&[
+            Point2f::new(0.0, 0.0),
+            Point2f::new(width, 0.0),
+            Point2f::new(width, height),
+            Point2f::new(0.0, height),
+        ]);
+        let mut projected_corners: Vector<Point2f> = Vector::default();
+        core::perspective_transform(&corners, &mut projected_corners, &homography)
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(Some(PatternLocation {
+            homography,
+            corners: projected_corners,
+            inliers,
+            error: distance / inliers as f64,
+        }))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use opencv::{core::{self, Mat, Scalar}, imgcodecs, prelude::*};
+    use crate::conf::PatternMatchConf;
+    use super::PatternMatcher;
+    ///
+    /// Returns the reference pattern image
+    fn pattern() -> Mat {
+        let img = imgcodecs::imread("./assets/patterns/pattern1.png", imgcodecs::IMREAD_COLOR).unwrap();
+        assert!(!img.empty());
+        img
+    }
+    #[test]
+    fn itself() {
+        let img = pattern();
+        let mut matcher = PatternMatcher::new(PatternMatchConf::default()).unwrap();
+        let result = matcher.eval(&img, &img).unwrap();
+        assert!(result.found, "good matches: {}, location: {:?}", result.good_matches.len(), result.location);
+        let location = result.location.unwrap();
+        assert!(location.error < 1.0, "error: {}", location.error);
+    }
+    #[test]
+    fn blank() {
+        let img = pattern();
+        let blank = Mat::new_rows_cols_with_default(img.rows(), img.cols(), core::CV_8UC3, Scalar::all(255.0)).unwrap();
+        let mut matcher = PatternMatcher::new(PatternMatchConf::default()).unwrap();
+        let result = matcher.eval(&img, &blank).unwrap();
+        assert!(!result.found);
+        assert!(result.good_matches.is_empty(), "good matches: {}", result.good_matches.len());
+        assert_eq!(result.confidence, 0.0);
+    }
+}
@@ -1,229 +1,492 @@
-use std::{path::PathBuf, time::Instant};
+use std::{collections::{BTreeMap, VecDeque}, path::{Path, PathBuf}, time::{Instant, SystemTime, UNIX_EPOCH}};
 
-use frdm_tools::{conf::{Conf, DetectingContoursConf, FastScanConf, FineScanConf}, ContextRead, DetectingContoursCv, DetectingContoursCvCtx, Eval, Image, Initial, InitialCtx, Threshold};
+use frdm_tools::{ContextRead, DetectingContoursCv, DetectingContoursCvCtx, Eval, Image, Initial, InitialCtx};
 use opencv::{
-    core::{self, Vector}, highgui, imgcodecs, imgproc, prelude::*, Result
+    core::{self, Rect, Vector}, highgui, imgcodecs, imgproc, prelude::*, Result
 };
 use sal_core::error::Error;
+use crate::{
+    conf::{PipelineConf, WarmUpConf},
+    frame_source::{self, Frame, FrameSource},
+    mosaic::{Mosaic, Tile},
+    results::{FrameRecord, ResultsWriter},
+    run_summary::RunSummary,
+    stage::{Pipeline, PixelFormat, StageCtx},
+    tuning::Tuning,
+    viewer::Viewer,
+};
 ///
-/// This algorithm combines statistical background image estimation and per-pixel Bayesian segmentation.
+/// Removes the static background from the sequence of the frames
 /// 
-/// It was introduced by Andrew B. Godbehere, Akihiro Matsukawa, and Ken Goldberg in their paper
-/// "Visual Tracking of Human Visitors under Variable-Lighting Conditions for a Responsive Audio Art Installation" in 2012.
-/// As per the paper, the system ran a successful interactive audio art installation called
-/// “Are We There Yet?” from March 31 - July 31 2011 at the Contemporary Jewish Museum in San Francisco, California.
+/// Each frame goes through the [Pipeline] configured by [PipelineConf]:
+/// - exposure normalization (gamma, brightness and contrast),
+/// - background subtraction, the selected algorithm (MOG, MOG2, KNN, GMG, CNT or GSOC)
+///   learns the background over the frames and returns the foreground mask,
+/// - morphological post-processing of the foreground mask (opening, closing, small blobs removal, etc.),
 /// 
-/// It uses first few (120 by default) frames for background modelling.
-/// It employs probabilistic foreground segmentation algorithm that identifies possible foreground objects using Bayesian inference.
-/// The estimates are adaptive; newer observations are more heavily weighted than old observations to accommodate variable illumination.
-/// Several morphological filtering operations like closing and opening are done to remove unwanted noise.
-/// You will get a black window during first few frames.
+/// the output of the last stage (the cleaned foreground mask by default) goes to the contour detection,
+/// configured by the `detecting-contours` section.
 /// 
-/// It would be better to apply morphological opening to the result to remove the noises. 
+/// The subtractor needs some frames to learn the background, the foreground mask is useless meanwhile,
+/// so the model can be learned before the run from the clean reference frames ([WarmUpConf]),
+/// or reloaded from the background image, saved by the previous run.
 /// 
 /// ### References:
 /// [Adaptive background mixture models for real-time tracking](http://www.ai.mit.edu/projects/vsam/Publications/stauffer_cvpr98_track.pdf)
 /// [OpenCV | Background Subtraction](https://docs.opencv.org/3.4/d8/d38/tutorial_bgsegm_bg_subtraction.html)
 /// 
 pub struct RemoveBackground {
-
+    source: Box<dyn FrameSource>,
+    conf: PipelineConf,
+    pipeline: Pipeline,
+    mosaic: Option<Mosaic>,
+    metrics: Option<PathBuf>,
+    results: Option<ResultsWriter>,
 }
 //
 //
 impl RemoveBackground {
-    pub fn new() -> Self {
-        Self {  }
+    ///
+    /// Returns [RemoveBackground] new instance
+    /// - `source` - source of the frames
+    /// - `conf` - parameters of the pipeline
+    /// 
+    /// The warm-up performed here if configured
+    pub fn new(source: Box<dyn FrameSource>, conf: PipelineConf) -> Result<Self, Error> {
+        let mut pipeline = Pipeline::from_conf(&conf.stages)?;
+        if let Some(warm_up) = &conf.warm_up {
+            Self::warm_up(&mut pipeline, warm_up)?;
+        }
+        Ok(Self {
+            source,
+            conf,
+            pipeline,
+            mosaic: None,
+            metrics: None,
+            results: None,
+        })
+    }
+    ///
+    /// Returns [RemoveBackground] rendering the outputs of all stages into the single [Mosaic] image,
+    /// shown in the single window by [RemoveBackground::tune]
+    /// and written into `output/Mosaic/<frame name>` by [RemoveBackground::eval]
+    pub fn mosaic(mut self, mosaic: Mosaic) -> Self {
+        self.mosaic = Some(mosaic);
+        self
+    }
+    ///
+    /// Returns [RemoveBackground] writing the run summary and the latency statistics of each stage
+    /// into the JSON file `path` at the end of [RemoveBackground::eval] and [RemoveBackground::view]
+    pub fn metrics(mut self, path: impl Into<PathBuf>) -> Self {
+        self.metrics = Some(path.into());
+        self
+    }
+    ///
+    /// Returns [RemoveBackground] writing the [FrameRecord] of each processed frame into the file `path`,
+    /// see [ResultsWriter] for the formats
+    pub fn results(mut self, path: impl AsRef<Path>) -> Result<Self, Error> {
+        self.results = Some(ResultsWriter::new(path)?);
+        Ok(self)
     }
     ///
-    /// Performs algoritm
-    pub fn eval(&self) -> Result<(), Error> {
+    /// Passes the clean reference frames through the `pipeline`, the learned state saved then
+    fn warm_up(pipeline: &mut Pipeline, conf: &WarmUpConf) -> Result<(), Error> {
         let dbg = "RemoveBackground";
-        let error = Error::new(dbg, "eval");
-        let dir = std::fs::read_dir("./assets/rope/").unwrap();
-        let mut paths: Vec<PathBuf> = dir.map(|p| p.unwrap().path()).skip(5).collect();
-
-        highgui::named_window("Frame", highgui::WINDOW_NORMAL)
-            .map_err(|err| error.pass(err.to_string()))?;
-        highgui::named_window("Gamma", highgui::WINDOW_NORMAL)
-            .map_err(|err| error.pass(err.to_string()))?;
-        highgui::named_window("BrightnessAndContrast", highgui::WINDOW_NORMAL)
-            .map_err(|err| error.pass(err.to_string()))?;
-        highgui::named_window("Result", highgui::WINDOW_NORMAL)
-            .map_err(|err| error.pass(err.to_string()))?;
-        
-        let history = 100;
-        let nmixtures = 5;
-        let background_ratio = 0.01;
-        let noise_sigma = 0.0;
-        let learning_rate = 0.5;
-        let mut fgbg = opencv::bgsegm::create_background_subtractor_mog(history, nmixtures, background_ratio, noise_sigma).unwrap();
-        let conf = Conf {
-            detecting_contours: DetectingContoursConf::default(),
-            fast_scan: FastScanConf {
-                geometry_defect_threshold: Threshold::min(),
-            },
-            fine_scan: FineScanConf {},
-        };
-        let fgmask = DetectingContoursCv::new(
-            conf.detecting_contours.clone(),
-            Initial::new(
-                InitialCtx::new(),
-            ),
-        );
-
-        let mut count = 0;
+        let mut source = frame_source::open(&conf.input, 0, None, conf.passes, false)?;
+        let mut summary = RunSummary::new();
+        while let Some(frame) = source.next_frame() {
+            let result = frame.and_then(|frame| {
+                let mut ctx = StageCtx {
+                    index: frame.index,
+                    path: frame.path.clone(),
+                    mask: None,
+                };
+                pipeline.warm_up(&frame.mat, &mut ctx).map(|_| frame.pass)
+            });
+            match result {
+                Ok(pass) => summary.processed(pass),
+                Err(err) => {
+                    log::warn!("{dbg}.warm_up | {:?}", err);
+                    summary.failed();
+                }
+            }
+        }
+        log::info!("{dbg}.warm_up | '{}' {}", conf.input, summary);
+        pipeline.save()
+    }
+    ///
+    /// Performs algoritm in the headless mode
+    /// 
+    /// Frames of the `source` processed until it exhausted,
+    /// the output of each stage written into `output/<Stage>/<frame name>`,
+    /// along with the mosaic if enabled
+    /// 
+    /// The frame, failed to be read, processed or written, is logged and skipped
+    pub fn eval(&mut self, output: impl AsRef<Path>) -> Result<(), Error> {
+        let dbg = "RemoveBackground";
+        let output = output.as_ref();
+        let fgmask = self.detecting_contours();
+        let mut summary = RunSummary::new();
         loop {
-            if count == 1 {
-                let dir = std::fs::read_dir("./assets/rope/").unwrap();
-                paths = dir.map(|p| p.unwrap().path()).skip(0).collect()
+            let time = Instant::now();
+            let Some(frame) = self.source.next_frame() else {
+                break;
             };
-            count += 1;
-            for path in paths.iter().cycle() {
-                if path.is_file() {
-                    log::debug!("{dbg}.eval | path: {}", path.display());
-                    match imgcodecs::imread(&path.to_str().unwrap(), imgcodecs::IMREAD_COLOR) {
-                        Ok(img) => {
-                            log::debug!("{dbg}.eval | file read successfully: {:?}", img.size());
-                            highgui::imshow("Frame", &img)
-                                .map_err(|err| error.pass(err.to_string()))?;
-                            let time = Instant::now();
-                            let gamma = self.auto_gamma(&img);
-                            println!("{dbg}.eval | Gamma elapsed: {:?}", time.elapsed());
-                            let time = Instant::now();
-                            let brc = self.auto_brightness_and_contrast(&gamma, Some(3.0))?;
-                            println!("{dbg}.eval | Brightness and contrast elapsed: {:?}", time.elapsed());
-                            let result = fgmask.eval(Image::new(brc.cols() as usize, brc.rows() as usize, brc.clone(), 0)).unwrap();
-                            let result: &DetectingContoursCvCtx = result.read();
-                            let result = &result.result.mat;
-                            // let mut result = unsafe { Mat::new_rows_cols(gamma.rows(), gamma.cols(), gamma.typ()).unwrap() };
-                            // fgbg.apply(&brc, &mut result, learning_rate)
-                            //     .map_err(|err| error.pass(err.to_string()))?;
-                            
-                            // let mut result = unsafe { Mat::new_rows_cols(img.rows(), img.cols(), img.typ()).unwrap() };
-                            // let images: opencv::core::Vector<Mat> = opencv::core::Vector::from_iter([
-                            //      img,
-                            //      fgmask,
-                            // ]);
-                            // opencv::core::hconcat(&images, &mut result)
-                            //     .map_err(|err| error.pass(err.to_string()))?;
-                            highgui::imshow("Gamma", &gamma)
-                                .map_err(|err| error.pass(err.to_string()))?;
-                            highgui::imshow("BrightnessAndContrast", &brc).unwrap();
-                                // .map_err(|err| error.pass(err.to_string()))?;
-                            highgui::imshow("Result", result)
-                                .map_err(|err| error.pass(err.to_string()))?;
-                            highgui::wait_key(100).unwrap();
-                        },
-                        Err(err) => log::warn!("{dbg}.eval | Read file '{}' error: {:?}", path.display(), err),
-                    };
+            summary.record(Self::READ, time.elapsed());
+            let result = frame.and_then(|frame| {
+                log::debug!("{dbg}.eval | pass: {}, frame: {}, timestamp: {:?}, path: {}, size: {:?}", frame.pass, frame.index, frame.timestamp, frame.path.display(), frame.mat.size());
+                self.write(&fgmask, &frame, output, &mut summary).map(|_| frame.pass)
+            });
+            match result {
+                Ok(pass) => summary.processed(pass),
+                Err(err) => {
+                    log::warn!("{dbg}.eval | {:?}", err);
+                    summary.failed();
                 }
-            }        
+            };
+        }
+        self.report(&summary)?;
+        self.pipeline.save()
+    }
+    ///
+    /// Writes the output of each stage for the single `frame` into `output/<Stage>/<frame name>`,
+    /// the latency of the stages recorded into the `summary`
+    fn write(&mut self, fgmask: &DetectingContoursCv, frame: &Frame, output: &Path, summary: &mut RunSummary) -> Result<(), Error> {
+        let error = Error::new("RemoveBackground", "write");
+        let (mut tiles, record) = self.process(fgmask, frame)?;
+        Self::record(&tiles, summary);
+        if let (Some(results), Some(record)) = (&mut self.results, &record) {
+            results.write(record)?;
         }
+        let time = Instant::now();
+        if let Some(mosaic) = &self.mosaic {
+            let img = mosaic.render(&tiles)
+                .map_err(|err| error.pass(format!("Frame '{}' mosaic error: {}", frame.path.display(), err)))?;
+            tiles.push(Tile::new(Self::MOSAIC, img, None));
+        }
+        for tile in tiles {
+            let dir = output.join(&tile.name);
+            std::fs::create_dir_all(&dir)
+                .map_err(|err| error.pass(format!("Create dir '{}' error: {}", dir.display(), err)))?;
+            let out = dir.join(&frame.name);
+            imgcodecs::imwrite(&out.to_string_lossy(), &tile.mat, &Vector::new())
+                .map_err(|err| error.pass(format!("Write '{}' error: {}", out.display(), err)))?;
+            log::debug!("RemoveBackground.write | written: {}", out.display());
+        }
+        summary.record(Self::WRITE, time.elapsed());
         Ok(())
     }
     ///
-    /// Step 1: Gamma correction
-    ///
-    /// The reasoning of this step is to balance out the contrast of the whole image
-    /// (since your image can be slightly overexposed/underexposed depending to the lighting condition).
-    ///
-    /// This may seem at first as an unnecessary step, but the importance of it cannot be underestimated:
-    /// in a sense, it normalizes the images to the similar distributions of exposures,
-    /// so that you can choose meaningful hyper-parameters later (e.g. the DELTA parameter in next section,
-    /// the noise filtering parameters, parameters for morphological stuffs, etc.)
-    fn auto_gamma(&self, img: &Mat) -> Mat {
-        // build a lookup table mapping the pixel values [0, 255] to
-        // their adjusted gamma values
-        let mid = 0.5f64;
-        let mean = opencv::core::mean(&img, &Mat::default()).unwrap().into_iter().take(3).map(|v| v as f64).sum::<f64>() / 3.0;
-        println!("mean: {:?}", mean);
-        let gamma: f64 = (mid * 255.0).ln()/mean.ln();
-        println!("gamma: {:?}", gamma);
-        let inv_gamma = 1.0 / gamma;
-        let table: Vec<_> = (0..256).map(|i| (255.0 * ((i as f64 / 255.0).powf(inv_gamma))) as u8 ).collect();
-        // println!("table: {:?}", table);
-        let mut dst = Mat::default();
-        opencv::core::lut(&img, &Mat::from_slice(&table).unwrap(), &mut dst).unwrap();
-        dst
-    }
-    fn auto_brightness_and_contrast(&self, img: &Mat, clip_hist_percent: Option<f32>) -> Result<Mat, Error> {
-        let mut clip_hist_percent = clip_hist_percent.unwrap_or(1.0);
+    /// Records the latency of each stage of the `tiles` into the `summary`
+    fn record(tiles: &[Tile], summary: &mut RunSummary) {
+        for tile in tiles {
+            if let Some(elapsed) = tile.elapsed {
+                summary.record(&tile.name, elapsed);
+            }
+        }
+    }
+    ///
+    /// Logs the `summary` of the run along with the latency of each stage,
+    /// written into the metrics file if enabled
+    fn report(&self, summary: &RunSummary) -> Result<(), Error> {
+        log::info!("RemoveBackground.report | {}", summary);
+        log::info!("RemoveBackground.report | Latency:\n{}", summary.metrics());
+        match &self.metrics {
+            Some(path) => summary.write(path),
+            None => Ok(()),
+        }
+    }
+    ///
+    /// Performs algoritm in the windowed mode
+    /// 
+    /// Frames of the `source` are shown by the `viewer` until it's closed,
+    /// the playback is controlled from the keyboard as described in [ViewerKey](crate::viewer::ViewerKey)
+    pub fn view(&mut self, mut viewer: Viewer) -> Result<(), Error> {
         let dbg = "RemoveBackground";
-        let error = Error::new(dbg, "auto_brightness_and_contrast");
-        let mut gray = Mat::default();
-        opencv::imgproc::cvt_color(img, &mut gray, imgproc::COLOR_BGR2GRAY, 0).unwrap();
-        let gray_channels = gray.channels();
-            // .map_err(|err| error.pass(err.to_string()))?;
-        println!("{dbg}.evauto_brightness_and_contrastal | gray channels: {:?}", gray.channels());
-        // highgui::imshow("BrightnessAndContrast", &gray).unwrap();
-            // .map_err(|err| error.pass(err.to_string()))?;
-        // highgui::wait_key(0).unwrap();
-
-        // Grayscale histogram
-        let mut hist = Mat::default();
-        let hist_size = 256 as i32;
-        let imgs: Vector<Mat> = Vector::from_iter([gray.clone()]);
-        opencv::imgproc::calc_hist(
-            &imgs,
-            &Vector::from_slice(&[0]),
-            &Mat::default(),
-            &mut hist,
-            &Vector::from_slice(&[hist_size]),
-            &Vector::from_slice(&[0.0 ,256.0]),
-            false,
-        ).unwrap();
-            // .map_err(|err| error.pass(err.to_string()))?;
-        // let hist_size = hist.len();
-        // println!("{dbg}.evauto_brightness_and_contrastal | hist: {:?}", hist);
-        log::warn!("{dbg}.evauto_brightness_and_contrastal | hist_size: {:?}", hist_size);
-
-        // highgui::imshow("BrightnessAndContrast", &hist).unwrap();
-        // highgui::wait_key(0).unwrap();
-
-        // Calculate cumulative distribution from the histogram
-        let mut accumulator = vec![];
-        accumulator.push(*hist.at::<f32>(0).unwrap());
-        for index in 1..(hist_size as usize) {
-            accumulator.push(accumulator[index -1] + *hist.at::<f32>(index as i32).unwrap())
+        let error = Error::new(dbg, "view");
+        let fgmask = self.detecting_contours();
+        let mut summary = RunSummary::new();
+        loop {
+            let mut fresh = false;
+            if viewer.wants_frame() {
+                let time = Instant::now();
+                let frame = self.source.next_frame();
+                summary.record(Self::READ, time.elapsed());
+                match frame {
+                    Some(Ok(frame)) => {
+                        log::debug!("{dbg}.view | pass: {}, frame: {}, timestamp: {:?}, path: {}, size: {:?}", frame.pass, frame.index, frame.timestamp, frame.path.display(), frame.mat.size());
+                        let result = self.process(&fgmask, &frame).and_then(|(tiles, record)| {
+                            Self::record(&tiles, &mut summary);
+                            if let (Some(results), Some(record)) = (&mut self.results, &record) {
+                                results.write(record)?;
+                            }
+                            viewer.push(frame.name.clone(), tiles)
+                        });
+                        match result {
+                            Ok(_) => {
+                                summary.processed(frame.pass);
+                                fresh = true;
+                            }
+                            Err(err) => {
+                                log::warn!("{dbg}.view | {:?}", err);
+                                summary.failed();
+                            }
+                        }
+                    }
+                    Some(Err(err)) => {
+                        log::warn!("{dbg}.view | {:?}", err);
+                        summary.failed();
+                    }
+                    None => viewer.exhausted(),
+                }
+            }
+            if !viewer.seeking() {
+                let time = Instant::now();
+                match viewer.show() {
+                    Ok(_) => if fresh {
+                        summary.record(Self::DISPLAY, time.elapsed());
+                    },
+                    Err(err) => log::warn!("{dbg}.view | {:?}", err),
+                }
+            }
+            let key = highgui::wait_key_ex(viewer.delay())
+                .map_err(|err| error.pass(err.to_string()))?;
+            if !viewer.key(key)? {
+                break;
+            }
         }
-        // println!("{dbg}.evauto_brightness_and_contrastal | accumulator: {:?}", accumulator);
-
-        // Locate points to clip
-        let maximum = accumulator.last().unwrap();
-        clip_hist_percent = clip_hist_percent * (maximum / 100.0);
-        clip_hist_percent = clip_hist_percent / 2.0;
-        println!("{dbg}.evauto_brightness_and_contrastal | maximum: {:?}", maximum);
-
-        // Locate left cut
-        let mut minimum_gray = 0;
-        while accumulator[minimum_gray] < clip_hist_percent {
-            minimum_gray += 1;
+        self.report(&summary)?;
+        self.pipeline.save()
+    }
+    ///
+    /// Number of the recent frames, the pipeline is re-run over when the parameters changed,
+    /// so the stateful stages (background subtraction) have something to learn from
+    const TUNING_FRAMES: usize = 30;
+    ///
+    /// Performs algoritm in the interactive tuning mode
+    /// 
+    /// Numeric parameters of the stages and of the contour detection are exposed as trackbars, see [Tuning],
+    /// once any of them changed the pipeline (and the contour detection, if it's parameters changed)
+    /// is rebuilt and re-run over the recent frames up to the current one.
+    /// 
+    /// Keys:
+    /// - `n` / `space` - next frame
+    /// - `s` - write the current parameters into the `output` config file
+    /// - `q` / `Esc` - quit, the current parameters written into the `output` config file
+    pub fn tune(&mut self, output: impl AsRef<Path>) -> Result<(), Error> {
+        let dbg = "RemoveBackground";
+        let output = output.as_ref();
+        self.windows()?;
+        let mut tuning = Tuning::new(&self.conf)?;
+        let mut fgmask = self.detecting_contours();
+        let mut frames: VecDeque<Frame> = VecDeque::with_capacity(Self::TUNING_FRAMES);
+        let mut summary = RunSummary::new();
+        let mut next = true;
+        loop {
+            if next {
+                next = false;
+                match self.source.next_frame() {
+                    Some(Ok(frame)) => {
+                        log::debug!("{dbg}.tune | pass: {}, frame: {}, path: {}", frame.pass, frame.index, frame.path.display());
+                        match self.process(&fgmask, &frame).and_then(|(tiles, _)| self.show(&tiles)) {
+                            Ok(_) => summary.processed(frame.pass),
+                            Err(err) => {
+                                log::warn!("{dbg}.tune | {:?}", err);
+                                summary.failed();
+                            }
+                        }
+                        if frames.len() == Self::TUNING_FRAMES {
+                            frames.pop_front();
+                        }
+                        frames.push_back(frame);
+                    }
+                    Some(Err(err)) => {
+                        log::warn!("{dbg}.tune | {:?}", err);
+                        summary.failed();
+                        next = true;
+                        continue;
+                    }
+                    None => log::info!("{dbg}.tune | Source exhausted, keep tuning on the last frame"),
+                }
+            }
+            if let Some(conf) = tuning.poll()? {
+                match Pipeline::from_conf(&conf.stages) {
+                    Ok(pipeline) => {
+                        self.pipeline = pipeline;
+                        let contours = conf.detecting_contours != self.conf.detecting_contours;
+                        self.conf = conf;
+                        if contours {
+                            fgmask = self.detecting_contours();
+                        }
+                        let mut tiles = vec![];
+                        for frame in &frames {
+                            match self.process(&fgmask, frame) {
+                                Ok((output, _)) => tiles = output,
+                                Err(err) => log::warn!("{dbg}.tune | {:?}", err),
+                            }
+                        }
+                        if !tiles.is_empty() {
+                            if let Err(err) = self.show(&tiles) {
+                                log::warn!("{dbg}.tune | {:?}", err);
+                            }
+                        }
+                    }
+                    Err(err) => log::warn!("{dbg}.tune | Pipeline error: {:?}", err),
+                }
+            }
+            match highgui::wait_key(50) {
+                Ok(key) if key == 'q' as i32 || key == 27 => break,
+                Ok(key) if key == 'n' as i32 || key == ' ' as i32 => next = true,
+                Ok(key) if key == 's' as i32 => self.conf.save(output)?,
+                Ok(_) => {}
+                Err(err) => log::warn!("{dbg}.tune | {:?}", err),
+            }
         }
-        println!("{dbg}.evauto_brightness_and_contrastal | minimum_gray: {:?}", minimum_gray);
-
-        // Locate right cut
-        let mut maximum_gray = (hist_size - 1) as usize;
-        while accumulator[maximum_gray] >= (maximum - clip_hist_percent) {
-            maximum_gray -= 1;
+        log::info!("{dbg}.tune | {}", summary);
+        self.conf.save(output)
+    }
+    ///
+    /// Creates the windows, the outputs shown in by [RemoveBackground::show]
+    fn windows(&self) -> Result<(), Error> {
+        let error = Error::new("RemoveBackground", "windows");
+        let windows = match self.mosaic {
+            Some(_) => vec![Self::MOSAIC.to_owned()],
+            None => self.names(),
+        };
+        for window in windows {
+            highgui::named_window(&window, highgui::WINDOW_NORMAL)
+                .map_err(|err| error.pass(err.to_string()))?;
         }
-        println!("{dbg}.evauto_brightness_and_contrastal | maximum_gray: {:?}", maximum_gray);
-
-        // Calculate alpha and beta values
-        let alpha = 255.0 / ((maximum_gray - minimum_gray) as f64);
-        let beta = - (minimum_gray as f64) * alpha;
-        println!("{dbg}.evauto_brightness_and_contrastal | alpha: {},   beta: {}", alpha, beta);
-        
-        // Calculate new histogram with desired range and show histogram 
-        // new_hist = cv2.calcHist([gray],[0],None,[256],[minimum_gray,maximum_gray])
-        // plt.plot(hist)
-        // plt.plot(new_hist)
-        // plt.xlim([0,256])
-        // plt.show()
-
-        let mut dst = Mat::default();
-        opencv::core::convert_scale_abs(img, &mut dst, alpha, beta).unwrap();
-            // .map_err(|err| error.pass(err.to_string()))?;
-        // return (auto_result, alpha, beta)
-        Ok(dst)
+        Ok(())
+    }
+    ///
+    /// Shows the `tiles` as the mosaic if enabled, each in it's own window otherwise
+    fn show(&self, tiles: &[Tile]) -> Result<(), Error> {
+        let error = Error::new("RemoveBackground", "show");
+        match &self.mosaic {
+            Some(mosaic) => {
+                let img = mosaic.render(tiles)?;
+                highgui::imshow(Self::MOSAIC, &img)
+                    .map_err(|err| error.pass(err.to_string()))?;
+            }
+            None => for tile in tiles {
+                highgui::imshow(&tile.name, &tile.mat)
+                    .map_err(|err| error.pass(err.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+    ///
+    /// Name of the source frame output
+    const FRAME: &'static str = "Frame";
+    ///
+    /// Name of the contour detection output
+    const RESULT: &'static str = "Result";
+    ///
+    /// Name of the mosaic output
+    const MOSAIC: &'static str = "Mosaic";
+    ///
+    /// Name of the frame reading latency metric
+    const READ: &'static str = "Read";
+    ///
+    /// Name of the outputs writing latency metric
+    const WRITE: &'static str = "Write";
+    ///
+    /// Name of the outputs displaying latency metric
+    const DISPLAY: &'static str = "Display";
+    ///
+    /// Returns names of the outputs, returned by [RemoveBackground::process], in the order
+    fn names(&self) -> Vec<String> {
+        let mut names = vec![Self::FRAME.to_owned()];
+        names.extend(self.pipeline.names());
+        names.push(Self::RESULT.to_owned());
+        names
+    }
+    ///
+    /// Returns contour detection algorithm configured by the `detecting-contours` section of the [PipelineConf]
+    fn detecting_contours(&self) -> DetectingContoursCv {
+        DetectingContoursCv::new(
+            self.conf.detecting_contours.clone(),
+            Initial::new(
+                InitialCtx::new(),
+            ),
+        )
+    }
+    ///
+    /// Returns the output of each stage for the single frame in the order of [RemoveBackground::names]
+    /// along with the values, calculated for the frame, if the results are written,
+    /// errors are annotated with the frame index and path
+    fn process(&mut self, fgmask: &DetectingContoursCv, frame: &Frame) -> Result<(Vec<Tile>, Option<FrameRecord>), Error> {
+        let error = Error::new("RemoveBackground", "process");
+        let context = |err: Error| error.pass(format!("Frame {} '{}' error: {}", frame.index, frame.path.display(), err));
+        let mut ctx = StageCtx {
+            index: frame.index,
+            path: frame.path.clone(),
+            mask: None,
+        };
+        let results = self.pipeline.eval(&frame.mat, &mut ctx)
+            .map_err(context)?;
+        let img = results.last().map_or(&frame.mat, |result| &result.output.mat);
+        let img = PixelFormat::of(img)
+            .and_then(|format| format.to_bgr8(img))
+            .map_err(context)?;
+        let time = Instant::now();
+        let result = fgmask.eval(Image::new(img.cols() as usize, img.rows() as usize, img, 0))
+            .map_err(|err| error.pass(format!("Frame {} '{}' contour detection error: {}", frame.index, frame.path.display(), err)))?;
+        let result: &DetectingContoursCvCtx = result.read();
+        let result = result.result.mat.clone();
+        let elapsed = time.elapsed();
+        let record = if self.results.is_some() {
+            let mut detection = Self::detection(&result)
+                .map_err(context)?;
+            detection.insert("elapsed_ms".to_owned(), elapsed.as_secs_f64() * 1000.0);
+            Some(FrameRecord {
+                path: frame.path.clone(),
+                name: frame.name.clone(),
+                pass: frame.pass,
+                index: frame.index,
+                timestamp_ms: frame.timestamp.as_secs_f64() * 1000.0,
+                processed_at_ms: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0.0, |time| time.as_secs_f64() * 1000.0),
+                stages: results.iter().map(|result| {
+                    let mut values = result.output.metadata.clone();
+                    values.insert("elapsed_ms".to_owned(), result.elapsed.as_secs_f64() * 1000.0);
+                    (result.name.clone(), values)
+                }).collect(),
+                detection,
+            })
+        } else {
+            None
+        };
+        let mut outputs = vec![Tile::new(Self::FRAME, frame.mat.clone(), None)];
+        outputs.extend(results.into_iter().map(|result| Tile::new(result.name, result.output.mat, Some(result.elapsed))));
+        outputs.push(Tile::new(Self::RESULT, result, Some(elapsed)));
+        Ok((outputs, record))
     }
-}
\ No newline at end of file
+    ///
+    /// Returns the values of the [DetectingContoursCvCtx] `result` image, the non-zero pixels of which are detected:
+    /// - `area` - number of the detected pixels
+    /// - `x`, `y`, `width`, `height` - bounding box of the detected pixels, zeros if nothing detected
+    fn detection(result: &Mat) -> Result<BTreeMap<String, f64>, Error> {
+        let error = Error::new("RemoveBackground", "detection");
+        let (area, bbox) = if result.empty() {
+            (0, Rect::default())
+        } else {
+            let img = PixelFormat::of(result)?.to_bgr8(result)?;
+            let mut gray = Mat::default();
+            imgproc::cvt_color(&img, &mut gray, imgproc::COLOR_BGR2GRAY, 0)
+                .map_err(|err| error.pass(err.to_string()))?;
+            let area = core::count_non_zero(&gray)
+                .map_err(|err| error.pass(err.to_string()))?;
+            let bbox = imgproc::bounding_rect(&gray)
+                .map_err(|err| error.pass(err.to_string()))?;
+            (area, bbox)
+        };
+        Ok(BTreeMap::from([
+            ("area".to_owned(), area as f64),
+            ("x".to_owned(), bbox.x as f64),
+            ("y".to_owned(), bbox.y as f64),
+            ("width".to_owned(), bbox.width as f64),
+            ("height".to_owned(), bbox.height as f64),
+        ]))
+    }
+}
@@ -0,0 +1,51 @@
+use std::{collections::BTreeMap, path::PathBuf};
+use serde::Serialize;
+///
+/// Per-frame results of the run
+/// 
+/// JSON Lines record:
+/// ```json
+/// {"path":"./assets/rope/001.png","name":"001.png","pass":0,"index":0,"timestamp_ms":0.0,"processed_at_ms":1739000000000.0,
+///  "stages":{"Gamma":{"elapsed_ms":1.2,"gamma":0.9,"mean":140.1}},"detection":{"area":1520.0,"elapsed_ms":3.1,"height":40.0,"width":38.0,"x":120.0,"y":64.0}}
+/// ```
+/// CSV columns are the same, nested values are flattened as `<stage>.<key>`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FrameRecord {
+    /// Path of the source, the frame belongs to
+    pub path: PathBuf,
+    /// Name of the frame
+    pub name: String,
+    /// Index of the pass over the source
+    pub pass: usize,
+    /// Index of the frame within the pass
+    pub index: usize,
+    /// Time of the frame within the source, ms
+    pub timestamp_ms: f64,
+    /// Wall clock time the frame processed at, ms since UNIX epoch
+    pub processed_at_ms: f64,
+    /// Values, calculated by each stage, along with the stage latency
+    pub stages: BTreeMap<String, BTreeMap<String, f64>>,
+    /// Outputs of the contour detection
+    pub detection: BTreeMap<String, f64>,
+}
+//
+//
+impl FrameRecord {
+    ///
+    /// Returns the flat `(column, value)` pairs of the record in the stable order
+    pub fn columns(&self) -> Vec<(String, String)> {
+        let mut columns = vec![
+            ("path".to_owned(), self.path.display().to_string()),
+            ("name".to_owned(), self.name.clone()),
+            ("pass".to_owned(), self.pass.to_string()),
+            ("index".to_owned(), self.index.to_string()),
+            ("timestamp_ms".to_owned(), self.timestamp_ms.to_string()),
+            ("processed_at_ms".to_owned(), self.processed_at_ms.to_string()),
+        ];
+        for (stage, values) in &self.stages {
+            columns.extend(values.iter().map(|(key, value)| (format!("{stage}.{key}"), value.to_string())));
+        }
+        columns.extend(self.detection.iter().map(|(key, value)| (format!("detection.{key}"), value.to_string())));
+        columns
+    }
+}
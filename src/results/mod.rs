@@ -0,0 +1,4 @@
+mod frame_record;
+mod results_writer;
+pub use frame_record::*;
+pub use results_writer::*;
@@ -0,0 +1,164 @@
+use std::{fs::File, io::{BufWriter, Write}, path::{Path, PathBuf}};
+use sal_core::error::Error;
+use super::FrameRecord;
+///
+/// Writes one [FrameRecord] per frame into the file,
+/// CSV for the `.csv` file, JSON Lines otherwise
+/// 
+/// CSV header is taken from the first record, the values of the columns,
+/// missing in the first record, are dropped with a warning
+/// 
+/// Each record is flushed immediately, so the file of the interrupted run is still usable
+pub struct ResultsWriter {
+    path: PathBuf,
+    csv: bool,
+    header: Option<Vec<String>>,
+    file: BufWriter<File>,
+}
+//
+//
+impl ResultsWriter {
+    ///
+    /// Returns [ResultsWriter] new instance, the file at `path` is created or truncated
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let error = Error::new("ResultsWriter", "new");
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| error.pass(format!("Create dir '{}' error: {}", dir.display(), err)))?;
+        }
+        let file = File::create(path)
+            .map_err(|err| error.pass(format!("Create file '{}' error: {}", path.display(), err)))?;
+        let csv = path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+        Ok(Self {
+            path: path.to_owned(),
+            csv,
+            header: None,
+            file: BufWriter::new(file),
+        })
+    }
+    ///
+    /// Writes the `record` into the file
+    pub fn write(&mut self, record: &FrameRecord) -> Result<(), Error> {
+        let error = Error::new("ResultsWriter", "write");
+        let line = if self.csv {
+            self.csv_line(record)?
+        } else {
+            serde_json::to_string(record)
+                .map_err(|err| error.pass(format!("Serialize '{}' error: {}", self.path.display(), err)))?
+        };
+        writeln!(self.file, "{}", line)
+            .and_then(|_| self.file.flush())
+            .map_err(|err| error.pass(format!("Write file '{}' error: {}", self.path.display(), err)))
+    }
+    ///
+    /// Returns the CSV line of the `record`, the header written before the first one
+    fn csv_line(&mut self, record: &FrameRecord) -> Result<String, Error> {
+        let error = Error::new("ResultsWriter", "csv_line");
+        let columns = record.columns();
+        let header = match &self.header {
+            Some(header) => header,
+            None => {
+                let header: Vec<String> = columns.iter().map(|(column, _)| column.clone()).collect();
+                writeln!(self.file, "{}", header.iter().map(|column| Self::escape(column)).collect::<Vec<_>>().join(","))
+                    .map_err(|err| error.pass(format!("Write file '{}' error: {}", self.path.display(), err)))?;
+                self.header.insert(header)
+            }
+        };
+        for (column, _) in &columns {
+            if !header.contains(column) {
+                log::warn!("ResultsWriter.csv_line | Column '{}' is missing in the header, dropped", column);
+            }
+        }
+        let line = header.iter()
+            .map(|column| columns.iter()
+                .find(|(key, _)| key == column)
+                .map_or(String::new(), |(_, value)| Self::escape(value)))
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(line)
+    }
+    ///
+    /// Returns the `value` quoted if it contains the separator, the quote or the line break
+    fn escape(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_owned()
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, path::PathBuf};
+    use serde_json::Value;
+    use crate::results::FrameRecord;
+    use super::ResultsWriter;
+    ///
+    /// Returns [FrameRecord] of the frame `name` with the single stage and the detection area
+    fn record(name: &str, index: usize) -> FrameRecord {
+        FrameRecord {
+            path: PathBuf::from("./assets/rope/"),
+            name: name.to_owned(),
+            pass: 0,
+            index,
+            timestamp_ms: 40.0,
+            processed_at_ms: 1000.5,
+            stages: BTreeMap::from([
+                ("Gamma".to_owned(), BTreeMap::from([("gamma".to_owned(), 1.5), ("elapsed_ms".to_owned(), 2.0)])),
+            ]),
+            detection: BTreeMap::from([("area".to_owned(), 120.0)]),
+        }
+    }
+    ///
+    /// Returns the lines, written by [ResultsWriter] of the `records` into the temporary file `name`
+    fn write(name: &str, records: &[FrameRecord]) -> Vec<String> {
+        let path = std::env::temp_dir().join(format!("results_writer_{}_{}", std::process::id(), name));
+        let mut writer = ResultsWriter::new(&path).unwrap();
+        for record in records {
+            writer.write(record).unwrap();
+        }
+        drop(writer);
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        text.lines().map(str::to_owned).collect()
+    }
+    #[test]
+    fn csv_header() {
+        let lines = write("header.csv", &[record("001.png", 0), record("002.png", 1)]);
+        assert_eq!(lines, [
+            "path,name,pass,index,timestamp_ms,processed_at_ms,Gamma.elapsed_ms,Gamma.gamma,detection.area",
+            "./assets/rope/,001.png,0,0,40,1000.5,2,1.5,120",
+            "./assets/rope/,002.png,0,1,40,1000.5,2,1.5,120",
+        ]);
+    }
+    #[test]
+    fn csv_missing_column() {
+        let mut second = record("002.png", 1);
+        second.stages.clear();
+        second.detection.insert("x".to_owned(), 3.0);
+        let lines = write("missing.csv", &[record("001.png", 0), second]);
+        // Missing values are empty, the values of the columns, missing in the header, are dropped
+        assert_eq!(lines[2], "./assets/rope/,002.png,0,1,40,1000.5,,,120");
+    }
+    #[test]
+    fn csv_escape() {
+        let lines = write("escape.csv", &[record("a,b \"c\".png", 0)]);
+        assert_eq!(lines[1], "./assets/rope/,\"a,b \"\"c\"\".png\",0,0,40,1000.5,2,1.5,120");
+        assert_eq!(ResultsWriter::escape("plain"), "plain");
+        assert_eq!(ResultsWriter::escape("two\nlines"), "\"two\nlines\"");
+    }
+    #[test]
+    fn jsonl_round_trip() {
+        let records = [record("001.png", 0), record("a,b \"c\".png", 1)];
+        let lines = write("round_trip.jsonl", &records);
+        assert_eq!(lines.len(), records.len());
+        for (line, record) in lines.iter().zip(&records) {
+            let value: Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value, serde_json::to_value(record).unwrap());
+            assert_eq!(value["name"], record.name);
+            assert_eq!(value["stages"]["Gamma"]["gamma"], 1.5);
+            assert_eq!(value["detection"]["area"], 120.0);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::{fmt::Display, path::Path, time::{Duration, Instant}};
+use sal_core::error::Error;
+use serde::Serialize;
+use crate::metrics::{Metrics, StageStats};
+///
+/// Counts frames processed during the run, along with the latency of each stage
+#[derive(Debug, Clone)]
+pub struct RunSummary {
+    processed: usize,
+    failed: usize,
+    passes: usize,
+    time: Instant,
+    metrics: Metrics,
+}
+//
+//
+impl RunSummary {
+    ///
+    /// Returns [RunSummary] new instance, run time is counted from now
+    pub fn new() -> Self {
+        Self {
+            processed: 0,
+            failed: 0,
+            passes: 0,
+            time: Instant::now(),
+            metrics: Metrics::new(),
+        }
+    }
+    ///
+    /// Counts successfully processed frame of the `pass`
+    pub fn processed(&mut self, pass: usize) {
+        self.processed += 1;
+        self.passes = self.passes.max(pass + 1);
+    }
+    ///
+    /// Counts frame, failed to be read or processed
+    pub fn failed(&mut self) {
+        self.failed += 1;
+    }
+    ///
+    /// Records the latency of the `stage` for the current frame
+    pub fn record(&mut self, stage: &str, elapsed: Duration) {
+        self.metrics.record(stage, elapsed);
+    }
+    ///
+    /// Returns the latency of each stage
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+    ///
+    /// Returns time elapsed since the run started
+    pub fn elapsed(&self) -> Duration {
+        self.time.elapsed()
+    }
+    ///
+    /// Returns processed frames per second
+    pub fn fps(&self) -> f64 {
+        let elapsed = self.elapsed().as_secs_f64();
+        if elapsed > 0.0 { self.processed as f64 / elapsed } else { 0.0 }
+    }
+    ///
+    /// Writes the summary along with the stage statistics into the JSON file `path`
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Report {
+            processed: usize,
+            failed: usize,
+            passes: usize,
+            elapsed_ms: f64,
+            fps: f64,
+            stages: Vec<StageStats>,
+        }
+        let path = path.as_ref();
+        let error = Error::new("RunSummary", "write");
+        let report = Report {
+            processed: self.processed,
+            failed: self.failed,
+            passes: self.passes,
+            elapsed_ms: self.elapsed().as_secs_f64() * 1000.0,
+            fps: self.fps(),
+            stages: self.metrics.stats(),
+        };
+        let text = serde_json::to_string_pretty(&report)
+            .map_err(|err| error.pass(format!("Serialize '{}' error: {}", path.display(), err)))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| error.pass(format!("Create dir '{}' error: {}", dir.display(), err)))?;
+        }
+        std::fs::write(path, text)
+            .map_err(|err| error.pass(format!("Write file '{}' error: {}", path.display(), err)))?;
+        log::info!("RunSummary.write | '{}' written", path.display());
+        Ok(())
+    }
+}
+//
+//
+impl Default for RunSummary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+//
+//
+impl Display for RunSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Done: {} frames processed, {} failed, {} passes, elapsed: {:?}, fps: {:.2}",
+            self.processed, self.failed, self.passes, self.elapsed(), self.fps(),
+        )
+    }
+}
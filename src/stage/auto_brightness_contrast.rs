@@ -0,0 +1,171 @@
+use opencv::{core::{Mat, Vector}, prelude::*};
+use sal_core::error::Error;
+use crate::conf::BrightnessContrastConf;
+use super::{BrightnessContrastResult, CorrectionError, Degenerate, PixelFormat, Roi, Smoothing, Stage, StageCtx, StageOutput};
+///
+/// Step 2: Automatic brightness and contrast optimization
+///
+/// The histogram of the grayscale image is clipped by `clip_hist_percent` from both sides,
+/// the remaining range of the gray levels is stretched to the full range of the depth
+/// 
+/// Supported pixel formats are described in [PixelFormat], the output has the format of the input,
+/// the histogram has a bin for each level of the depth, 256 or 65536
+/// 
+/// Degenerate images are handled as described in [Degenerate],
+/// black and saturated ones are detected by [Degenerate::of_mean] of the gray level,
+/// the image is flat if the histogram cuts meet each other
+pub struct AutoBrightnessContrast {
+    conf: BrightnessContrastConf,
+    smoothing: Option<Smoothing<2>>,
+    roi: Option<Roi>,
+}
+//
+//
+impl AutoBrightnessContrast {
+    ///
+    /// Returns [AutoBrightnessContrast] new instance
+    pub fn new(conf: BrightnessContrastConf) -> Result<Self, Error> {
+        Ok(Self {
+            smoothing: conf.smoothing.clone().map(Smoothing::new),
+            roi: conf.roi.as_ref().map(Roi::new).transpose()?,
+            conf,
+        })
+    }
+    ///
+    /// Returns corrected image along with the calculated correction parameters and histogram,
+    /// alpha and beta are smoothed across frames if enabled
+    /// - `mask` - region of the image, the histogram calculated on, empty for whole image
+    /// 
+    /// Returns [CorrectionError::Degenerate] with [Degenerate::Empty] if the image or the `mask` has no pixels
+    pub fn auto_brightness_and_contrast(&mut self, img: &Mat, mask: &Mat) -> Result<BrightnessContrastResult, CorrectionError> {
+        let mut clip_hist_percent = self.conf.clip_hist_percent;
+        let dbg = "AutoBrightnessContrast";
+        let error = Error::new(dbg, "auto_brightness_and_contrast");
+        if img.empty() {
+            return Err(CorrectionError::Degenerate(Degenerate::Empty));
+        }
+        let format = PixelFormat::of(img)?;
+        let gray = format.to_gray(img)?;
+        // Grayscale histogram
+        let mut hist = Mat::default();
+        let hist_size = format.levels() as i32;
+        let imgs: Vector<Mat> = Vector::from_iter([gray]);
+        opencv::imgproc::calc_hist(
+            &imgs,
+            &Vector::from_slice(&[0]),
+            mask,
+            &mut hist,
+            &Vector::from_slice(&[hist_size]),
+            &Vector::from_slice(&[0.0, hist_size as f32]),
+            false,
+        ).map_err(|err| error.pass(err.to_string()))?;
+        let hist: Vec<f32> = (0..hist_size)
+            .map(|index| hist.at::<f32>(index).copied())
+            .collect::<Result<_, _>>()
+            .map_err(|err| error.pass(err.to_string()))?;
+        log::trace!("{dbg}.auto_brightness_and_contrast | hist_size: {}", hist_size);
+        // Calculate cumulative distribution from the histogram
+        let mut accumulator = Vec::with_capacity(hist.len());
+        let mut sum = 0.0;
+        for value in &hist {
+            sum += value;
+            accumulator.push(sum);
+        }
+        // Locate points to clip
+        let maximum = sum;
+        if maximum <= 0.0 {
+            return Err(CorrectionError::Degenerate(Degenerate::Empty));
+        }
+        clip_hist_percent = clip_hist_percent * (maximum / 100.0);
+        clip_hist_percent = clip_hist_percent / 2.0;
+        // Locate left cut
+        let mut minimum_gray = 0;
+        while minimum_gray < (hist_size - 1) as usize && accumulator[minimum_gray] < clip_hist_percent {
+            minimum_gray += 1;
+        }
+        // Locate right cut
+        let mut maximum_gray = (hist_size - 1) as usize;
+        while maximum_gray > minimum_gray && accumulator[maximum_gray] >= (maximum - clip_hist_percent) {
+            maximum_gray -= 1;
+        }
+        log::trace!("{dbg}.auto_brightness_and_contrast | maximum: {}, minimum_gray: {}, maximum_gray: {}", maximum, minimum_gray, maximum_gray);
+        let mean = hist.iter().enumerate().map(|(level, count)| level as f64 * *count as f64).sum::<f64>() / maximum as f64;
+        // Calculate alpha and beta values
+        let (alpha, beta, degenerate) = if let Some(degenerate) = Degenerate::of_mean(mean, format.max()) {
+            log::warn!("{dbg}.auto_brightness_and_contrast | {}, mean: {}, alpha 1.0, beta 0.0 used", degenerate, mean);
+            (1.0, 0.0, Some(degenerate))
+        } else if maximum_gray <= minimum_gray {
+            log::warn!("{dbg}.auto_brightness_and_contrast | {}, gray level: {}, alpha 1.0, beta 0.0 used", Degenerate::Flat, minimum_gray);
+            (1.0, 0.0, Some(Degenerate::Flat))
+        } else {
+            let alpha = format.max() / ((maximum_gray - minimum_gray) as f64);
+            let beta = - (minimum_gray as f64) * alpha;
+            log::trace!("{dbg}.auto_brightness_and_contrast | alpha: {}, beta: {}", alpha, beta);
+            let [alpha, beta] = match &mut self.smoothing {
+                Some(smoothing) => smoothing.update([alpha, beta]),
+                None => [alpha, beta],
+            };
+            (alpha, beta, None)
+        };
+        let dst = format.scale(img, alpha, beta)?;
+        Ok(BrightnessContrastResult {
+            mat: dst,
+            alpha,
+            beta,
+            minimum_gray,
+            maximum_gray,
+            hist,
+            degenerate,
+        })
+    }
+}
+//
+//
+impl Stage for AutoBrightnessContrast {
+    fn name(&self) -> &str {
+        "BrightnessAndContrast"
+    }
+    fn eval(&mut self, img: &Mat, ctx: &mut StageCtx) -> Result<StageOutput, Error> {
+        let mask = match &self.roi {
+            Some(roi) => roi.mask(img, ctx)?,
+            None => Mat::default(),
+        };
+        let error = Error::new("AutoBrightnessContrast", "eval");
+        let result = self.auto_brightness_and_contrast(img, &mask)
+            .map_err(|err| err.into_error(&error))?;
+        Ok(StageOutput::new(result.mat, [
+            ("alpha", result.alpha),
+            ("beta", result.beta),
+            ("minimum_gray", result.minimum_gray as f64),
+            ("maximum_gray", result.maximum_gray as f64),
+            ("degenerate", if result.degenerate.is_some() { 1.0 } else { 0.0 }),
+        ]))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use opencv::core::{self, Mat, Scalar};
+    use crate::conf::BrightnessContrastConf;
+    use super::{AutoBrightnessContrast, Degenerate};
+    ///
+    /// Returns the kind of the degenerate 8-bit BGR image, all pixels of the gray `level`
+    fn degenerate(level: f64) -> Option<Degenerate> {
+        let img = Mat::new_rows_cols_with_default(8, 8, core::CV_8UC3, Scalar::all(level)).unwrap();
+        let mut stage = AutoBrightnessContrast::new(BrightnessContrastConf::default()).unwrap();
+        let result = stage.auto_brightness_and_contrast(&img, &Mat::default()).unwrap();
+        assert_eq!((result.alpha, result.beta), (1.0, 0.0));
+        result.degenerate
+    }
+    #[test]
+    fn black() {
+        assert_eq!(degenerate(0.0), Some(Degenerate::Black));
+    }
+    #[test]
+    fn saturated() {
+        assert_eq!(degenerate(255.0), Some(Degenerate::Saturated));
+    }
+    #[test]
+    fn flat() {
+        assert_eq!(degenerate(128.0), Some(Degenerate::Flat));
+    }
+}
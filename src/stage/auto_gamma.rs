@@ -0,0 +1,133 @@
+use opencv::{core::Mat, prelude::*};
+use sal_core::error::Error;
+use crate::conf::AutoGammaConf;
+use super::{CorrectionError, Degenerate, GammaResult, PixelFormat, Roi, Smoothing, Stage, StageCtx, StageOutput};
+///
+/// Step 1: Gamma correction
+///
+/// The reasoning of this step is to balance out the contrast of the whole image
+/// (since your image can be slightly overexposed/underexposed depending to the lighting condition).
+///
+/// This may seem at first as an unnecessary step, but the importance of it cannot be underestimated:
+/// in a sense, it normalizes the images to the similar distributions of exposures,
+/// so that you can choose meaningful hyper-parameters later (e.g. the DELTA parameter in next section,
+/// the noise filtering parameters, parameters for morphological stuffs, etc.)
+/// 
+/// Supported pixel formats are described in [PixelFormat], the output has the format of the input,
+/// 65536-entry table is used for the 16-bit images
+/// 
+/// Degenerate images are handled as described in [Degenerate],
+/// black and saturated ones are detected by [Degenerate::of_mean]
+pub struct AutoGamma {
+    conf: AutoGammaConf,
+    smoothing: Option<Smoothing<1>>,
+    roi: Option<Roi>,
+}
+//
+//
+impl AutoGamma {
+    ///
+    /// Returns [AutoGamma] new instance
+    pub fn new(conf: AutoGammaConf) -> Result<Self, Error> {
+        Ok(Self {
+            smoothing: conf.smoothing.clone().map(Smoothing::new),
+            roi: conf.roi.as_ref().map(Roi::new).transpose()?,
+            conf,
+        })
+    }
+    ///
+    /// Returns gamma corrected image along with the mean brightness and the gamma,
+    /// gamma is smoothed across frames if enabled
+    /// - `mask` - region of the image, the mean brightness calculated on, empty for whole image
+    /// 
+    /// Returns [CorrectionError::Degenerate] with [Degenerate::Empty] if the image or the `mask` has no pixels
+    pub fn auto_gamma(&mut self, img: &Mat, mask: &Mat) -> Result<GammaResult, CorrectionError> {
+        let mid = self.conf.mid;
+        let error = Error::new("AutoGamma", "auto_gamma");
+        if img.empty() || (!mask.empty() && opencv::core::count_non_zero(mask).map_err(|err| error.pass(err.to_string()))? == 0) {
+            return Err(CorrectionError::Degenerate(Degenerate::Empty));
+        }
+        let format = PixelFormat::of(img)?;
+        let max = format.max();
+        let mean = format.color_mean(img, mask)?;
+        log::trace!("AutoGamma.auto_gamma | mean: {}", mean);
+        let degenerate = Degenerate::of_mean(mean, max);
+        let gamma = match degenerate {
+            Some(degenerate) => {
+                log::warn!("AutoGamma.auto_gamma | {}, mean: {}, gamma 1.0 used", degenerate, mean);
+                1.0
+            }
+            None => {
+                // Relative to the range, so the same image of any depth gets the same gamma,
+                // the mean mapped to the `mid` by the table below
+                let gamma: f64 = (mean / max).ln() / mid.ln();
+                log::trace!("AutoGamma.auto_gamma | gamma: {}", gamma);
+                match &mut self.smoothing {
+                    Some(smoothing) => smoothing.update([gamma])[0],
+                    None => gamma,
+                }
+            }
+        };
+        // build a lookup table mapping the pixel values [0, max] to
+        // their adjusted gamma values
+        let inv_gamma = 1.0 / gamma;
+        let table: Vec<_> = (0..format.levels()).map(|i| max * ((i as f64 / max).powf(inv_gamma))).collect();
+        let dst = format.apply_table(img, &table)?;
+        Ok(GammaResult {
+            mat: dst,
+            mean,
+            gamma,
+            degenerate,
+        })
+    }
+}
+//
+//
+impl Stage for AutoGamma {
+    fn name(&self) -> &str {
+        "Gamma"
+    }
+    fn eval(&mut self, img: &Mat, ctx: &mut StageCtx) -> Result<StageOutput, Error> {
+        let mask = match &self.roi {
+            Some(roi) => roi.mask(img, ctx)?,
+            None => Mat::default(),
+        };
+        let error = Error::new("AutoGamma", "eval");
+        let result = self.auto_gamma(img, &mask)
+            .map_err(|err| err.into_error(&error))?;
+        Ok(StageOutput::new(result.mat, [
+            ("mean", result.mean),
+            ("gamma", result.gamma),
+            ("degenerate", if result.degenerate.is_some() { 1.0 } else { 0.0 }),
+        ]))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use opencv::{core::{self, Mat, Scalar}, prelude::*};
+    use crate::conf::AutoGammaConf;
+    use super::{AutoGamma, GammaResult};
+    ///
+    /// Returns the gamma correction of the `img` with the default parameters
+    fn gamma(img: &Mat) -> GammaResult {
+        let mut stage = AutoGamma::new(AutoGammaConf::default()).unwrap();
+        stage.auto_gamma(img, &Mat::default()).unwrap()
+    }
+    #[test]
+    fn depth_independent() {
+        let img8 = Mat::new_rows_cols_with_default(8, 8, core::CV_8UC1, Scalar::all(64.0)).unwrap();
+        let mut img16 = Mat::default();
+        img8.convert_to(&mut img16, core::CV_16U, 257.0, 0.0).unwrap();
+        let (result8, result16) = (gamma(&img8), gamma(&img16));
+        assert!((result8.gamma - result16.gamma).abs() < 1e-9, "8-bit: {}, 16-bit: {}", result8.gamma, result16.gamma);
+        assert!(result8.gamma > 1.0, "dark image expected to be brightened, gamma: {}", result8.gamma);
+        assert_eq!(result8.degenerate, None);
+    }
+    #[test]
+    fn mean_to_mid() {
+        let img = Mat::new_rows_cols_with_default(8, 8, core::CV_8UC3, Scalar::all(64.0)).unwrap();
+        let result = gamma(&img);
+        let mean = core::mean(&result.mat, &Mat::default()).unwrap()[0];
+        assert!((mean - 0.5 * 255.0).abs() <= 1.0, "mean: {}", mean);
+    }
+}
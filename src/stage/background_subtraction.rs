@@ -0,0 +1,207 @@
+use opencv::{bgsegm, core::{self, Mat, Ptr, Scalar, Vector}, imgcodecs, imgproc, prelude::*, video};
+use sal_core::error::Error;
+use crate::conf::{BackgroundModelConf, BackgroundSubtractionConf, SubtractorConf};
+use super::{Degenerate, PixelFormat, Stage, StageCtx, StageOutput};
+///
+/// Step 3: Background subtraction
+///
+/// Builds the model of the static background over the frames
+/// and returns the foreground mask of the current frame, 8-bit single channel,
+/// 255 - foreground, 0 - background; shadows (127, MOG2 / KNN with `detect-shadows`)
+/// are thresholded into the background, so the mask is always binary
+/// 
+/// The algorithm is selected by the [SubtractorConf], the input is converted into 8-bit BGR,
+/// the foreground mask also becomes the [StageCtx::mask] for the following stages
+/// 
+/// The model can be learned in advance from the clean reference frames, see [Stage::warm_up],
+/// and persisted as the background image, described in [BackgroundModelConf];
+/// the background image is taken from the subtractor if supported (MOG2, KNN, CNT, GSOC),
+/// otherwise estimated as the running average of the background pixels
+/// 
+/// ### References:
+/// [OpenCV | Background Subtraction](https://docs.opencv.org/3.4/d8/d38/tutorial_bgsegm_bg_subtraction.html)
+pub struct BackgroundSubtraction {
+    conf: BackgroundSubtractionConf,
+    subtractor: Ptr<BackgroundSubtractor>,
+    /// Running average of the background pixels, 32-bit float BGR, empty until the first frame
+    background: Mat,
+}
+//
+//
+impl BackgroundSubtraction {
+    ///
+    /// Returns [BackgroundSubtraction] new instance
+    /// 
+    /// The background image loaded and replayed if enabled in the [BackgroundModelConf]
+    pub fn new(conf: BackgroundSubtractionConf) -> Result<Self, Error> {
+        let mut stage = Self {
+            subtractor: Self::subtractor(&conf.subtractor)?,
+            background: Mat::default(),
+            conf,
+        };
+        if let Some(model) = stage.conf.model.clone() {
+            if model.load && model.path.exists() {
+                stage.load(&model)?;
+            }
+        }
+        Ok(stage)
+    }
+    ///
+    /// Weight of the current frame in the running average of the background
+    const BACKGROUND_RATE: f64 = 0.05;
+    ///
+    /// Returns background subtractor selected by the `conf`
+    fn subtractor(conf: &SubtractorConf) -> Result<Ptr<BackgroundSubtractor>, Error> {
+        let error = Error::new("BackgroundSubtraction", "subtractor");
+        let subtractor: opencv::Result<Ptr<BackgroundSubtractor>> = match conf {
+            SubtractorConf::Mog(conf) => bgsegm::create_background_subtractor_mog(
+                conf.history,
+                conf.nmixtures,
+                conf.background_ratio,
+                conf.noise_sigma,
+            ).map(Ptr::from),
+            SubtractorConf::Mog2(conf) => video::create_background_subtractor_mog2(
+                conf.history,
+                conf.var_threshold,
+                conf.detect_shadows,
+            ).map(Ptr::from),
+            SubtractorConf::Knn(conf) => video::create_background_subtractor_knn(
+                conf.history,
+                conf.dist2_threshold,
+                conf.detect_shadows,
+            ).map(Ptr::from),
+            SubtractorConf::Gmg(conf) => bgsegm::create_background_subtractor_gmg(
+                conf.initialization_frames,
+                conf.decision_threshold,
+            ).map(Ptr::from),
+            SubtractorConf::Cnt(conf) => bgsegm::create_background_subtractor_cnt(
+                conf.min_pixel_stability,
+                conf.use_history,
+                conf.max_pixel_stability,
+                conf.is_parallel,
+            ).map(Ptr::from),
+            SubtractorConf::Gsoc(conf) => bgsegm::create_background_subtractor_gsoc(
+                conf.mc,
+                conf.n_samples,
+                conf.replace_rate,
+                conf.propagation_rate,
+                conf.hits_threshold,
+                conf.alpha,
+                conf.beta,
+                conf.blinking_supression_decay,
+                conf.blinking_supression_multiplier,
+                conf.noise_removal_threshold_fac_bg,
+                conf.noise_removal_threshold_fac_fg,
+            ).map(Ptr::from),
+        };
+        subtractor.map_err(|err| error.pass(err.to_string()))
+    }
+    ///
+    /// Feeds the background image, stored in the `model` file, to the subtractor
+    fn load(&mut self, model: &BackgroundModelConf) -> Result<(), Error> {
+        let error = Error::new("BackgroundSubtraction", "load");
+        let path = &model.path;
+        let img = imgcodecs::imread(&path.to_string_lossy(), imgcodecs::IMREAD_COLOR)
+            .map_err(|err| error.pass(format!("Read background '{}' error: {}", path.display(), err)))?;
+        if img.empty() {
+            return Err(error.err(format!("Read background '{}' error: empty image", path.display())));
+        }
+        let replay = model.replay.unwrap_or_else(|| self.conf.subtractor.history());
+        for _ in 0..replay {
+            self.apply(&img, -1.0)?;
+        }
+        log::info!("BackgroundSubtraction.load | Background '{}' loaded, replayed {} times", path.display(), replay);
+        Ok(())
+    }
+    ///
+    /// Gray level of the shadow in the mask of the subtractor, the mask is thresholded above
+    const SHADOW: f64 = 127.0;
+    ///
+    /// Returns the binary foreground mask of the 8-bit BGR `img`,
+    /// the running average of the background updated if the model persistence is enabled
+    fn apply(&mut self, img: &Mat, learning_rate: f64) -> Result<Mat, Error> {
+        let error = Error::new("BackgroundSubtraction", "apply");
+        let mut raw = Mat::default();
+        self.subtractor.apply(img, &mut raw, learning_rate)
+            .map_err(|err| error.pass(err.to_string()))?;
+        let mut mask = Mat::default();
+        imgproc::threshold(&raw, &mut mask, Self::SHADOW, 255.0, imgproc::THRESH_BINARY)
+            .map_err(|err| error.pass(err.to_string()))?;
+        if self.conf.model.is_some() {
+            if self.background.empty() || self.background.size().ok() != img.size().ok() {
+                img.convert_to(&mut self.background, core::CV_32F, 1.0, 0.0)
+                    .map_err(|err| error.pass(err.to_string()))?;
+            } else {
+                let mut background_mask = Mat::default();
+                core::compare(&mask, &Scalar::all(0.0), &mut background_mask, core::CMP_EQ)
+                    .map_err(|err| error.pass(err.to_string()))?;
+                imgproc::accumulate_weighted(img, &mut self.background, Self::BACKGROUND_RATE, &background_mask)
+                    .map_err(|err| error.pass(err.to_string()))?;
+            }
+        }
+        Ok(mask)
+    }
+    ///
+    /// Returns the output of the stage with the foreground mask
+    fn output(mask: Mat) -> Result<StageOutput, Error> {
+        let error = Error::new("BackgroundSubtraction", "output");
+        let foreground = core::count_non_zero(&mask)
+            .map_err(|err| error.pass(err.to_string()))?;
+        let total = mask.total() as f64;
+        Ok(StageOutput::new(mask, [
+            ("foreground", if total > 0.0 { foreground as f64 / total } else { 0.0 }),
+        ]))
+    }
+}
+//
+//
+impl Stage for BackgroundSubtraction {
+    fn name(&self) -> &str {
+        "BackgroundSubtraction"
+    }
+    fn eval(&mut self, img: &Mat, ctx: &mut StageCtx) -> Result<StageOutput, Error> {
+        let error = Error::new("BackgroundSubtraction", "eval");
+        if img.empty() {
+            return Err(error.err(Degenerate::Empty.to_string()));
+        }
+        let img = PixelFormat::of(img)?.to_bgr8(img)?;
+        let mask = self.apply(&img, self.conf.learning_rate)?;
+        ctx.mask = Some(mask.clone());
+        Self::output(mask)
+    }
+    fn warm_up(&mut self, img: &Mat, ctx: &mut StageCtx) -> Result<StageOutput, Error> {
+        let error = Error::new("BackgroundSubtraction", "warm_up");
+        if img.empty() {
+            return Err(error.err(Degenerate::Empty.to_string()));
+        }
+        let img = PixelFormat::of(img)?.to_bgr8(img)?;
+        let mask = self.apply(&img, self.conf.warm_up_learning_rate)?;
+        ctx.mask = Some(mask.clone());
+        Self::output(mask)
+    }
+    fn save(&mut self) -> Result<(), Error> {
+        let error = Error::new("BackgroundSubtraction", "save");
+        let model = match &self.conf.model {
+            Some(model) if model.save => model,
+            _ => return Ok(()),
+        };
+        let mut background = Mat::default();
+        if self.subtractor.get_background_image(&mut background).is_err() || background.empty() {
+            if self.background.empty() {
+                log::warn!("BackgroundSubtraction.save | Background isn't learned yet, nothing to save");
+                return Ok(());
+            }
+            self.background.convert_to(&mut background, core::CV_8U, 1.0, 0.0)
+                .map_err(|err| error.pass(err.to_string()))?;
+        }
+        let path = &model.path;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| error.pass(format!("Create dir '{}' error: {}", dir.display(), err)))?;
+        }
+        imgcodecs::imwrite(&path.to_string_lossy(), &background, &Vector::new())
+            .map_err(|err| error.pass(format!("Write background '{}' error: {}", path.display(), err)))?;
+        log::info!("BackgroundSubtraction.save | Background saved to '{}'", path.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use opencv::core::Mat;
+use super::Degenerate;
+///
+/// Result of the [AutoBrightnessContrast](super::AutoBrightnessContrast)
+#[derive(Debug, Clone)]
+pub struct BrightnessContrastResult {
+    /// Corrected image
+    pub mat: Mat,
+    /// Contrast gain, applied to the image
+    pub alpha: f64,
+    /// Brightness offset, applied to the image
+    pub beta: f64,
+    /// Gray level of the left histogram cut, mapped to 0
+    pub minimum_gray: usize,
+    /// Gray level of the right histogram cut, mapped to the maximum of the depth
+    pub maximum_gray: usize,
+    /// Grayscale histogram of the source image, a bin for each level of the depth
+    pub hist: Vec<f32>,
+    /// Kind of the degenerate image, identity correction applied to
+    pub degenerate: Option<Degenerate>,
+}
@@ -0,0 +1,47 @@
+use std::fmt::Display;
+use sal_core::error::Error;
+use super::Degenerate;
+///
+/// Error of the automatic correction, the [Degenerate] image can be matched on
+/// 
+/// Converted into the [Error] by the [Stage::eval](super::Stage::eval),
+/// the message of the degenerate kind is kept as is
+#[derive(Debug)]
+pub enum CorrectionError {
+    /// The correction can't be calculated for the image
+    Degenerate(Degenerate),
+    /// Any other failure, OpenCV call or unsupported pixel format
+    Failed(Error),
+}
+//
+//
+impl CorrectionError {
+    ///
+    /// Returns [Error] in the context of the `error`, the degenerate kind becomes it's message
+    pub fn into_error(self, error: &Error) -> Error {
+        match self {
+            CorrectionError::Degenerate(degenerate) => error.err(degenerate.to_string()),
+            CorrectionError::Failed(err) => err,
+        }
+    }
+}
+//
+//
+impl From<Error> for CorrectionError {
+    fn from(err: Error) -> Self {
+        Self::Failed(err)
+    }
+}
+//
+//
+impl Display for CorrectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorrectionError::Degenerate(degenerate) => write!(f, "{}", degenerate),
+            CorrectionError::Failed(err) => write!(f, "{:?}", err),
+        }
+    }
+}
+//
+//
+impl std::error::Error for CorrectionError {}
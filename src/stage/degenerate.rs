@@ -0,0 +1,55 @@
+use std::fmt::Display;
+///
+/// Kind of the image, the automatic correction can't be calculated for
+/// 
+/// - [Degenerate::Empty] is an error, the stage returns it as [CorrectionError::Degenerate](super::CorrectionError)
+/// - others are recoverable, the stage falls back to the identity correction
+///   (gamma 1.0, alpha 1.0, beta 0.0), the image passed as is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Degenerate {
+    /// Image or it's region of interest has no pixels
+    Empty,
+    /// Mean brightness about the bottom of the range
+    Black,
+    /// Mean brightness about the top of the range
+    Saturated,
+    /// All pixels have about the same gray level
+    Flat,
+}
+//
+//
+impl Degenerate {
+    ///
+    /// Mean brightness relative to the range, the image considered black below
+    pub const BLACK: f64 = 1.0 / 255.0;
+    ///
+    /// Mean brightness relative to the range, the image considered saturated above
+    pub const SATURATED: f64 = 254.0 / 255.0;
+    ///
+    /// Returns [Degenerate::Black] or [Degenerate::Saturated] for the `mean` brightness
+    /// of the image with the maximum pixel value `max`, `None` otherwise
+    pub fn of_mean(mean: f64, max: f64) -> Option<Self> {
+        if mean <= Self::BLACK * max {
+            Some(Self::Black)
+        } else if mean >= Self::SATURATED * max {
+            Some(Self::Saturated)
+        } else {
+            None
+        }
+    }
+}
+//
+//
+impl Display for Degenerate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Degenerate::Empty => write!(f, "Degenerate image: empty"),
+            Degenerate::Black => write!(f, "Degenerate image: black"),
+            Degenerate::Saturated => write!(f, "Degenerate image: saturated"),
+            Degenerate::Flat => write!(f, "Degenerate image: flat"),
+        }
+    }
+}
+//
+//
+impl std::error::Error for Degenerate {}
@@ -0,0 +1,15 @@
+use opencv::core::Mat;
+use super::Degenerate;
+///
+/// Result of the [AutoGamma](super::AutoGamma)
+#[derive(Debug, Clone)]
+pub struct GammaResult {
+    /// Corrected image
+    pub mat: Mat,
+    /// Mean brightness of the source image
+    pub mean: f64,
+    /// Gamma, applied to the image
+    pub gamma: f64,
+    /// Kind of the degenerate image, identity correction applied to
+    pub degenerate: Option<Degenerate>,
+}
@@ -0,0 +1,52 @@
+mod auto_brightness_contrast;
+mod auto_gamma;
+mod background_subtraction;
+mod brightness_contrast_result;
+mod correction_error;
+mod degenerate;
+mod gamma_result;
+mod morphology;
+mod pipeline;
+mod pixel_format;
+mod roi;
+mod smoothing;
+mod stage_ctx;
+mod stage_output;
+pub use auto_brightness_contrast::*;
+pub use auto_gamma::*;
+pub use background_subtraction::*;
+pub use brightness_contrast_result::*;
+pub use correction_error::*;
+pub use degenerate::*;
+pub use gamma_result::*;
+pub use morphology::*;
+pub use pipeline::*;
+pub use pixel_format::*;
+pub use roi::*;
+pub use smoothing::*;
+pub use stage_ctx::*;
+pub use stage_output::*;
+use opencv::core::Mat;
+use sal_core::error::Error;
+///
+/// Single step of the image processing [Pipeline]
+pub trait Stage {
+    ///
+    /// Returns the name of the stage, used as window title and output directory
+    fn name(&self) -> &str;
+    ///
+    /// Returns the output of the stage for the `input` image
+    /// - `ctx` - the context of the frame, shared between all stages of the pipeline
+    fn eval(&mut self, input: &Mat, ctx: &mut StageCtx) -> Result<StageOutput, Error>;
+    ///
+    /// Returns the output of the stage for the clean reference `input` image during the warm-up,
+    /// same as [Stage::eval] by default
+    fn warm_up(&mut self, input: &Mat, ctx: &mut StageCtx) -> Result<StageOutput, Error> {
+        self.eval(input, ctx)
+    }
+    ///
+    /// Stores the state, learned by the stage, nothing to store by default
+    fn save(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
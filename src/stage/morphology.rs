@@ -0,0 +1,138 @@
+use opencv::{core::{self, Mat, Point, Scalar, Size, Vector}, imgproc, prelude::*};
+use sal_core::error::Error;
+use crate::conf::{KernelShape, MorphologyConf, MorphologyOpConf};
+use super::{Degenerate, PixelFormat, Stage, StageCtx, StageOutput};
+///
+/// Step 4: Morphological post-processing of the foreground mask
+/// 
+/// Removes the noise and fills the gaps of the foreground mask before the contour detection,
+/// operations are described in [MorphologyOpConf], applied in the configured order
+/// 
+/// The input is expected to be 8-bit single channel mask, other formats are converted to 8-bit gray,
+/// the output mask becomes the [StageCtx::mask] for the following stages
+/// 
+/// ### References:
+/// [OpenCV | Morphological Transformations](https://docs.opencv.org/4.x/d9/d61/tutorial_py_morphological_ops.html)
+pub struct Morphology {
+    conf: MorphologyConf,
+    kernel: Mat,
+}
+//
+//
+impl Morphology {
+    ///
+    /// Returns [Morphology] new instance
+    pub fn new(conf: MorphologyConf) -> Result<Self, Error> {
+        let error = Error::new("Morphology", "new");
+        let shape = match conf.kernel.shape {
+            KernelShape::Rect => imgproc::MORPH_RECT,
+            KernelShape::Ellipse => imgproc::MORPH_ELLIPSE,
+            KernelShape::Cross => imgproc::MORPH_CROSS,
+        };
+        let kernel = imgproc::get_structuring_element(shape, Size::new(conf.kernel.width, conf.kernel.height), Point::new(-1, -1))
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(Self { conf, kernel })
+    }
+    ///
+    /// Returns the `mask` with the morphological operation `op` applied
+    fn morphology_ex(&self, mask: &Mat, op: i32, iterations: i32) -> Result<Mat, Error> {
+        let error = Error::new("Morphology", "morphology_ex");
+        let mut dst = Mat::default();
+        let border_value = imgproc::morphology_default_border_value()
+            .map_err(|err| error.pass(err.to_string()))?;
+        imgproc::morphology_ex(mask, &mut dst, op, &self.kernel, Point::new(-1, -1), iterations, core::BORDER_CONSTANT, border_value)
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(dst)
+    }
+    ///
+    /// Returns the `mask` without connected blobs with the area less than `min_area`,
+    /// along with the number of removed blobs
+    fn remove_small_blobs(mask: &Mat, min_area: i32) -> Result<(Mat, usize), Error> {
+        let error = Error::new("Morphology", "remove_small_blobs");
+        let mut labels = Mat::default();
+        let mut stats = Mat::default();
+        let mut centroids = Mat::default();
+        let count = imgproc::connected_components_with_stats(mask, &mut labels, &mut stats, &mut centroids, 8, core::CV_32S)
+            .map_err(|err| error.pass(err.to_string()))?;
+        // Label 0 is the background
+        let mut small = vec![false; count.max(0) as usize];
+        for label in 1..count {
+            let area = *stats.at_2d::<i32>(label, imgproc::CC_STAT_AREA)
+                .map_err(|err| error.pass(err.to_string()))?;
+            small[label as usize] = area < min_area;
+        }
+        let removed = small.iter().filter(|small| **small).count();
+        let mut dst = mask.try_clone()
+            .map_err(|err| error.pass(err.to_string()))?;
+        if removed > 0 {
+            let labels = labels.data_typed::<i32>()
+                .map_err(|err| error.pass(err.to_string()))?;
+            let pixels = dst.data_bytes_mut()
+                .map_err(|err| error.pass(err.to_string()))?;
+            for (pixel, label) in pixels.iter_mut().zip(labels) {
+                if small[*label as usize] {
+                    *pixel = 0;
+                }
+            }
+        }
+        Ok((dst, removed))
+    }
+    ///
+    /// Returns the `mask` with the background regions, enclosed by the foreground, filled
+    fn fill_holes(mask: &Mat) -> Result<Mat, Error> {
+        let error = Error::new("Morphology", "fill_holes");
+        let mut contours: Vector<Vector<Point>> = Vector::new();
+        imgproc::find_contours(mask, &mut contours, imgproc::RETR_EXTERNAL, imgproc::CHAIN_APPROX_SIMPLE, Point::new(0, 0))
+            .map_err(|err| error.pass(err.to_string()))?;
+        let mut dst = mask.try_clone()
+            .map_err(|err| error.pass(err.to_string()))?;
+        imgproc::draw_contours(&mut dst, &contours, -1, Scalar::all(255.0), imgproc::FILLED, imgproc::LINE_8, &core::no_array(), i32::MAX, Point::new(0, 0))
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(dst)
+    }
+}
+//
+//
+impl Stage for Morphology {
+    fn name(&self) -> &str {
+        "Morphology"
+    }
+    fn eval(&mut self, img: &Mat, ctx: &mut StageCtx) -> Result<StageOutput, Error> {
+        let error = Error::new("Morphology", "eval");
+        if img.empty() {
+            return Err(error.err(Degenerate::Empty.to_string()));
+        }
+        let mut mask = if img.typ() == core::CV_8UC1 {
+            img.try_clone().map_err(|err| error.pass(err.to_string()))?
+        } else {
+            let bgr = PixelFormat::of(img)?.to_bgr8(img)?;
+            let mut gray = Mat::default();
+            imgproc::cvt_color(&bgr, &mut gray, imgproc::COLOR_BGR2GRAY, 0)
+                .map_err(|err| error.pass(err.to_string()))?;
+            gray
+        };
+        let mut removed = 0;
+        for op in &self.conf.operations {
+            mask = match op {
+                MorphologyOpConf::Open { iterations } => self.morphology_ex(&mask, imgproc::MORPH_OPEN, *iterations)?,
+                MorphologyOpConf::Close { iterations } => self.morphology_ex(&mask, imgproc::MORPH_CLOSE, *iterations)?,
+                MorphologyOpConf::Dilate { iterations } => self.morphology_ex(&mask, imgproc::MORPH_DILATE, *iterations)?,
+                MorphologyOpConf::Erode { iterations } => self.morphology_ex(&mask, imgproc::MORPH_ERODE, *iterations)?,
+                MorphologyOpConf::RemoveSmallBlobs { min_area } => {
+                    let (dst, count) = Self::remove_small_blobs(&mask, *min_area)?;
+                    removed += count;
+                    dst
+                }
+                MorphologyOpConf::FillHoles => Self::fill_holes(&mask)?,
+            };
+        }
+        let foreground = core::count_non_zero(&mask)
+            .map_err(|err| error.pass(err.to_string()))?;
+        let total = mask.total() as f64;
+        ctx.mask = Some(mask.clone());
+        Ok(StageOutput::new(mask, [
+            ("foreground", if total > 0.0 { foreground as f64 / total } else { 0.0 }),
+            ("blobs_removed", removed as f64),
+        ]))
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::Instant;
+use opencv::core::Mat;
+use sal_core::error::Error;
+use crate::conf::StageConf;
+use super::{AutoBrightnessContrast, AutoGamma, BackgroundSubtraction, Morphology, Stage, StageCtx, StageOutput, StageResult};
+///
+/// Ordered chain of the [Stage]s, output of each stage is the input of the next one
+/// 
+/// Built from the config:
+/// ```ignore
+/// let pipeline = Pipeline::from_conf(&conf.stages)?;
+/// ```
+/// or stage by stage:
+/// ```ignore
+/// let pipeline = Pipeline::new()
+///     .stage(AutoGamma::new(AutoGammaConf::default())?)
+///     .stage(AutoBrightnessContrast::new(BrightnessContrastConf::default())?)
+///     .stage(BackgroundSubtraction::new(BackgroundSubtractionConf::default())?)
+///     .stage(Morphology::new(MorphologyConf::default())?);
+/// ```
+#[derive(Default)]
+pub struct Pipeline {
+    stages: Vec<Box<dyn Stage>>,
+    /// Mask of the previous frame, see [StageCtx::mask]
+    mask: Option<Mat>,
+}
+//
+//
+impl Pipeline {
+    ///
+    /// Returns empty [Pipeline]
+    pub fn new() -> Self {
+        Self { stages: vec![], mask: None }
+    }
+    ///
+    /// Returns [Pipeline] with stages built from the `conf` in the same order
+    pub fn from_conf(conf: &[StageConf]) -> Result<Self, Error> {
+        let mut pipeline = Self::new();
+        for conf in conf {
+            pipeline = match conf {
+                StageConf::AutoGamma(conf) => pipeline.stage(AutoGamma::new(conf.clone())?),
+                StageConf::BrightnessContrast(conf) => pipeline.stage(AutoBrightnessContrast::new(conf.clone())?),
+                StageConf::BackgroundSubtraction(conf) => pipeline.stage(BackgroundSubtraction::new(conf.clone())?),
+                StageConf::Morphology(conf) => pipeline.stage(Morphology::new(conf.clone())?),
+            };
+        }
+        Ok(pipeline)
+    }
+    ///
+    /// Returns the pipeline with the `stage` appended to the end
+    pub fn stage(mut self, stage: impl Stage + 'static) -> Self {
+        self.stages.push(Box::new(stage));
+        self
+    }
+    ///
+    /// Returns names of the stages in the order
+    pub fn names(&self) -> Vec<String> {
+        self.stages.iter().map(|stage| stage.name().to_owned()).collect()
+    }
+    ///
+    /// Returns outputs of all stages for the `input` image in the order
+    /// 
+    /// The mask, produced for the frame, is passed to the next one through the [StageCtx::mask],
+    /// unless the `ctx` already has the mask
+    pub fn eval(&mut self, input: &Mat, ctx: &mut StageCtx) -> Result<Vec<StageResult>, Error> {
+        let error = Error::new("Pipeline", "eval");
+        if ctx.mask.is_none() {
+            ctx.mask = self.mask.take();
+        }
+        let mut results: Vec<StageResult> = Vec::with_capacity(self.stages.len());
+        for stage in self.stages.iter_mut() {
+            let input = results.last().map_or(input, |result| &result.output.mat);
+            let time = Instant::now();
+            let output = stage.eval(input, ctx)
+                .map_err(|err| error.pass(format!("Stage '{}' error: {}", stage.name(), err)))?;
+            let elapsed = time.elapsed();
+            log::debug!("Pipeline.eval | {} elapsed: {:?}, {:?}", stage.name(), elapsed, output.metadata);
+            results.push(StageResult {
+                name: stage.name().to_owned(),
+                output,
+                elapsed,
+            });
+        }
+        self.mask = ctx.mask.clone();
+        Ok(results)
+    }
+    ///
+    /// Passes the clean reference `input` image through all stages, see [Stage::warm_up]
+    pub fn warm_up(&mut self, input: &Mat, ctx: &mut StageCtx) -> Result<(), Error> {
+        let error = Error::new("Pipeline", "warm_up");
+        let mut output: Option<StageOutput> = None;
+        for stage in self.stages.iter_mut() {
+            let input = output.as_ref().map_or(input, |output| &output.mat);
+            let next = stage.warm_up(input, ctx)
+                .map_err(|err| error.pass(format!("Stage '{}' error: {}", stage.name(), err)))?;
+            output = Some(next);
+        }
+        Ok(())
+    }
+    ///
+    /// Stores the state, learned by the stages, see [Stage::save]
+    pub fn save(&mut self) -> Result<(), Error> {
+        let error = Error::new("Pipeline", "save");
+        for stage in self.stages.iter_mut() {
+            stage.save()
+                .map_err(|err| error.pass(format!("Stage '{}' error: {}", stage.name(), err)))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use opencv::{core::{self, Mat, Vector}, imgproc, prelude::*};
+use sal_core::error::Error;
+///
+/// Pixel format of the image, supported by the preprocessing stages:
+/// 8-bit or 16-bit unsigned depth with 1 (gray), 3 (BGR) or 4 (BGRA) channels
+/// 
+/// The alpha channel of the BGRA image is never corrected, only passed through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelFormat {
+    depth: i32,
+    channels: i32,
+}
+//
+//
+impl PixelFormat {
+    ///
+    /// Returns [PixelFormat] of the `img`, error if the format isn't supported
+    pub fn of(img: &Mat) -> Result<Self, Error> {
+        let error = Error::new("PixelFormat", "of");
+        let depth = img.depth();
+        let channels = img.channels();
+        if !(depth == core::CV_8U || depth == core::CV_16U) || !matches!(channels, 1 | 3 | 4) {
+            return Err(error.err(format!(
+                "Unsupported image type {}, expected CV_8UC1, CV_8UC3, CV_8UC4 or CV_16UC1, CV_16UC3, CV_16UC4",
+                core::type_to_string(img.typ()).unwrap_or_else(|_| img.typ().to_string()),
+            )));
+        }
+        Ok(Self { depth, channels })
+    }
+    ///
+    /// Returns the maximum value of the pixel channel, 255 or 65535
+    pub fn max(&self) -> f64 {
+        if self.depth == core::CV_16U { u16::MAX as f64 } else { u8::MAX as f64 }
+    }
+    ///
+    /// Returns the number of the levels of the pixel channel, 256 or 65536
+    pub fn levels(&self) -> usize {
+        self.max() as usize + 1
+    }
+    ///
+    /// Returns the mean of the color channels over the non-zero pixels of the `mask`,
+    /// the alpha channel isn't counted
+    pub fn color_mean(&self, img: &Mat, mask: &Mat) -> Result<f64, Error> {
+        let error = Error::new("PixelFormat", "color_mean");
+        let mean = core::mean(img, mask).map_err(|err| error.pass(err.to_string()))?;
+        let channels = self.channels.min(3) as usize;
+        Ok(mean.into_iter().take(channels).sum::<f64>() / channels as f64)
+    }
+    ///
+    /// Returns grayscale version of the `img` of the same depth
+    pub fn to_gray(&self, img: &Mat) -> Result<Mat, Error> {
+        let error = Error::new("PixelFormat", "to_gray");
+        let code = match self.channels {
+            1 => return Ok(img.clone()),
+            3 => imgproc::COLOR_BGR2GRAY,
+            _ => imgproc::COLOR_BGRA2GRAY,
+        };
+        let mut gray = Mat::default();
+        imgproc::cvt_color(img, &mut gray, code, 0)
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(gray)
+    }
+    ///
+    /// Returns the `img` with the `op` applied to the color channels,
+    /// the alpha channel of the BGRA image passed through as is
+    pub fn map_color(&self, img: &Mat, op: impl FnOnce(&Mat) -> Result<Mat, Error>) -> Result<Mat, Error> {
+        let error = Error::new("PixelFormat", "map_color");
+        if self.channels != 4 {
+            return op(img);
+        }
+        let mut channels: Vector<Mat> = Vector::new();
+        core::split(img, &mut channels).map_err(|err| error.pass(err.to_string()))?;
+        let alpha = channels.get(3).map_err(|err| error.pass(err.to_string()))?;
+        let color_channels: Vector<Mat> = channels.iter().take(3).collect();
+        let mut color = Mat::default();
+        core::merge(&color_channels, &mut color).map_err(|err| error.pass(err.to_string()))?;
+        let color = op(&color)?;
+        let mut channels: Vector<Mat> = Vector::new();
+        core::split(&color, &mut channels).map_err(|err| error.pass(err.to_string()))?;
+        channels.push(alpha);
+        let mut dst = Mat::default();
+        core::merge(&channels, &mut dst).map_err(|err| error.pass(err.to_string()))?;
+        Ok(dst)
+    }
+    ///
+    /// Returns the `img` with the color channels mapped through the `table`,
+    /// the `table` must contain [PixelFormat::levels] entries
+    pub fn apply_table(&self, img: &Mat, table: &[f64]) -> Result<Mat, Error> {
+        let error = Error::new("PixelFormat", "apply_table");
+        if table.len() != self.levels() {
+            return Err(error.err(format!("Table expected {} entries, but got {}", self.levels(), table.len())));
+        }
+        let max = self.max();
+        let depth = self.depth;
+        self.map_color(img, |img| {
+            if depth == core::CV_8U {
+                let table: Vec<u8> = table.iter().map(|v| v.round().clamp(0.0, max) as u8).collect();
+                let table = Mat::from_slice(&table).map_err(|err| error.pass(err.to_string()))?;
+                let mut dst = Mat::default();
+                core::lut(img, &table, &mut dst).map_err(|err| error.pass(err.to_string()))?;
+                Ok(dst)
+            } else {
+                // `core::lut` supports 8-bit sources only, so 16-bit values are mapped in place
+                let table: Vec<u16> = table.iter().map(|v| v.round().clamp(0.0, max) as u16).collect();
+                let mut dst = img.try_clone().map_err(|err| error.pass(err.to_string()))?;
+                let bytes = dst.data_bytes_mut().map_err(|err| error.pass(err.to_string()))?;
+                for value in bytes.chunks_exact_mut(2) {
+                    let mapped = table[u16::from_ne_bytes([value[0], value[1]]) as usize];
+                    value.copy_from_slice(&mapped.to_ne_bytes());
+                }
+                Ok(dst)
+            }
+        })
+    }
+    ///
+    /// Returns the `img` with the color channels scaled as `alpha * value + beta`,
+    /// saturated to the range of the depth
+    pub fn scale(&self, img: &Mat, alpha: f64, beta: f64) -> Result<Mat, Error> {
+        let error = Error::new("PixelFormat", "scale");
+        self.map_color(img, |img| {
+            let mut dst = Mat::default();
+            img.convert_to(&mut dst, img.typ(), alpha, beta).map_err(|err| error.pass(err.to_string()))?;
+            Ok(dst)
+        })
+    }
+    ///
+    /// Returns the `img` converted to the 8-bit BGR image
+    pub fn to_bgr8(&self, img: &Mat) -> Result<Mat, Error> {
+        let error = Error::new("PixelFormat", "to_bgr8");
+        let img = if self.depth == core::CV_16U {
+            let mut dst = Mat::default();
+            img.convert_to(&mut dst, core::CV_8U, 1.0 / 257.0, 0.0).map_err(|err| error.pass(err.to_string()))?;
+            dst
+        } else {
+            img.clone()
+        };
+        let code = match self.channels {
+            3 => return Ok(img),
+            1 => imgproc::COLOR_GRAY2BGR,
+            _ => imgproc::COLOR_BGRA2BGR,
+        };
+        let mut dst = Mat::default();
+        imgproc::cvt_color(&img, &mut dst, code, 0)
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(dst)
+    }
+}
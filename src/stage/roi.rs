@@ -0,0 +1,87 @@
+use opencv::{core::{self, Mat, Rect, Scalar, Size}, imgcodecs, imgproc, prelude::*};
+use sal_core::error::Error;
+use crate::conf::RoiConf;
+use super::StageCtx;
+///
+/// Region of the image, the statistics of the stage calculated on
+pub enum Roi {
+    /// Static rectangle
+    Rect(Rect),
+    /// Static mask image, non-zero pixels are selected
+    Mask(Mat),
+    /// Foreground mask, produced by the pipeline, [StageCtx::mask]:
+    /// of the current frame by the preceding stage, of the previous frame otherwise
+    Stage,
+}
+//
+//
+impl Roi {
+    ///
+    /// Returns [Roi] new instance, mask file loaded if specified
+    pub fn new(conf: &RoiConf) -> Result<Self, Error> {
+        let error = Error::new("Roi", "new");
+        match conf {
+            RoiConf::Rect { x, y, width, height } => Ok(Self::Rect(Rect::new(*x, *y, *width, *height))),
+            RoiConf::Mask { path } => {
+                let mask = imgcodecs::imread(&path.to_string_lossy(), imgcodecs::IMREAD_GRAYSCALE)
+                    .map_err(|err| error.pass(format!("Read mask '{}' error: {}", path.display(), err)))?;
+                if mask.empty() {
+                    return Err(error.err(format!("Read mask '{}' error: empty image", path.display())));
+                }
+                Ok(Self::Mask(mask))
+            }
+            RoiConf::Stage => Ok(Self::Stage),
+        }
+    }
+    ///
+    /// Returns 8-bit single channel mask of the `img` size,
+    /// empty mask returned if the region isn't available, meaning whole image
+    pub fn mask(&self, img: &Mat, ctx: &StageCtx) -> Result<Mat, Error> {
+        let error = Error::new("Roi", "mask");
+        let size = img.size().map_err(|err| error.pass(err.to_string()))?;
+        match self {
+            Roi::Rect(rect) => {
+                let mut mask = Mat::new_rows_cols_with_default(size.height, size.width, core::CV_8UC1, Scalar::all(0.0))
+                    .map_err(|err| error.pass(err.to_string()))?;
+                let x = rect.x.min(size.width);
+                let y = rect.y.min(size.height);
+                let rect = Rect::new(x, y, rect.width.min(size.width - x), rect.height.min(size.height - y));
+                if rect.width > 0 && rect.height > 0 {
+                    imgproc::rectangle(&mut mask, rect, Scalar::all(255.0), imgproc::FILLED, imgproc::LINE_8, 0)
+                        .map_err(|err| error.pass(err.to_string()))?;
+                } else {
+                    log::warn!("Roi.mask | Rect {:?} is out of the image {:?}, whole image used", rect, size);
+                    return Ok(Mat::default());
+                }
+                Ok(mask)
+            }
+            Roi::Mask(mask) => Self::fit(mask, size),
+            Roi::Stage => match &ctx.mask {
+                Some(mask) => {
+                    // No foreground found, nothing to select
+                    if core::count_non_zero(mask).map_err(|err| error.pass(err.to_string()))? == 0 {
+                        log::debug!("Roi.mask | Empty mask, whole image used");
+                        return Ok(Mat::default());
+                    }
+                    Self::fit(mask, size)
+                }
+                None => {
+                    log::debug!("Roi.mask | No mask produced yet, whole image used");
+                    Ok(Mat::default())
+                }
+            },
+        }
+    }
+    ///
+    /// Returns the `mask` resized to the `size` if required
+    fn fit(mask: &Mat, size: Size) -> Result<Mat, Error> {
+        let error = Error::new("Roi", "fit");
+        if mask.size().map_err(|err| error.pass(err.to_string()))? == size {
+            return Ok(mask.clone());
+        }
+        let mut dst = Mat::default();
+        imgproc::resize(mask, &mut dst, size, 0.0, 0.0, imgproc::INTER_NEAREST)
+            .map_err(|err| error.pass(err.to_string()))?;
+        Ok(dst)
+    }
+}
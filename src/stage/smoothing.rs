@@ -0,0 +1,83 @@
+use crate::conf::SmoothingConf;
+///
+/// Exponential moving average of the correction values across frames
+/// 
+/// The first value is the key one, the average is reset to the current values,
+/// if the key value deviates from it's average more than `scene_change` relatively
+#[derive(Debug, Clone)]
+pub struct Smoothing<const N: usize> {
+    conf: SmoothingConf,
+    values: Option<[f64; N]>,
+}
+//
+//
+impl<const N: usize> Smoothing<N> {
+    ///
+    /// Returns [Smoothing] new instance
+    pub fn new(conf: SmoothingConf) -> Self {
+        Self { conf, values: None }
+    }
+    ///
+    /// Returns smoothed `values` of the current frame
+    pub fn update(&mut self, values: [f64; N]) -> [f64; N] {
+        let factor = self.conf.factor;
+        let smoothed = match self.values {
+            Some(average) if !self.is_scene_change(&average, &values) => {
+                std::array::from_fn(|i| average[i] + factor * (values[i] - average[i]))
+            }
+            Some(_) => {
+                log::debug!("Smoothing.update | Scene change detected, values reset to: {:?}", values);
+                values
+            }
+            None => values,
+        };
+        self.values = Some(smoothed);
+        smoothed
+    }
+    ///
+    /// Returns true if the key value deviates from it's average more than `scene_change` relatively
+    fn is_scene_change(&self, average: &[f64; N], values: &[f64; N]) -> bool {
+        match (average.first(), values.first()) {
+            (Some(average), Some(value)) => {
+                !average.is_finite() || (value - average).abs() > self.conf.scene_change * average.abs().max(f64::EPSILON)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::conf::SmoothingConf;
+    use super::Smoothing;
+    ///
+    /// Returns [Smoothing] of the `factor`, scene change above 50 %
+    fn smoothing(factor: f64) -> Smoothing<2> {
+        Smoothing::new(SmoothingConf { factor, scene_change: 0.5 })
+    }
+    #[test]
+    fn first() {
+        let mut smoothing = smoothing(0.2);
+        assert_eq!(smoothing.update([1.5, -10.0]), [1.5, -10.0]);
+    }
+    #[test]
+    fn converges() {
+        let mut smoothing = smoothing(0.2);
+        smoothing.update([1.0, 0.0]);
+        let second = smoothing.update([1.2, 20.0]);
+        assert!((second[0] - 1.04).abs() < 1e-9, "second: {:?}", second);
+        assert!((second[1] - 4.0).abs() < 1e-9, "second: {:?}", second);
+        let mut values = second;
+        for _ in 0..100 {
+            values = smoothing.update([1.2, 20.0]);
+        }
+        assert!((values[0] - 1.2).abs() < 1e-6, "values: {:?}", values);
+        assert!((values[1] - 20.0).abs() < 1e-6, "values: {:?}", values);
+    }
+    #[test]
+    fn scene_change() {
+        let mut smoothing = smoothing(0.2);
+        smoothing.update([1.0, 0.0]);
+        // Key value deviates from the average more than 50 %, the average reset
+        assert_eq!(smoothing.update([2.0, 5.0]), [2.0, 5.0]);
+    }
+}
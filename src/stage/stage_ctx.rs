@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+use opencv::core::Mat;
+///
+/// Context of the frame, shared between all stages of the [Pipeline](super::Pipeline)
+#[derive(Debug, Clone, Default)]
+pub struct StageCtx {
+    /// Index of the frame within the pass
+    pub index: usize,
+    /// Path of the source, the frame belongs to
+    pub path: PathBuf,
+    /// Mask of the region of interest, 8-bit single channel, non-zero pixels are selected,
+    /// the foreground mask of the previous frame at the beginning of the [Pipeline](super::Pipeline),
+    /// replaced by the stage, producing the mask of the current frame, for the following ones
+    pub mask: Option<Mat>,
+}
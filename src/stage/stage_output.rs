@@ -0,0 +1,36 @@
+use std::{collections::BTreeMap, time::Duration};
+use opencv::core::Mat;
+///
+/// Output of the single [Stage](super::Stage)
+#[derive(Debug, Clone, Default)]
+pub struct StageOutput {
+    /// Resulting image
+    pub mat: Mat,
+    /// Named values, calculated by the stage
+    pub metadata: BTreeMap<String, f64>,
+}
+//
+//
+impl StageOutput {
+    ///
+    /// Returns [StageOutput] new instance
+    /// - `mat` - resulting image
+    /// - `metadata` - named values, calculated by the stage
+    pub fn new(mat: Mat, metadata: impl IntoIterator<Item = (&'static str, f64)>) -> Self {
+        Self {
+            mat,
+            metadata: metadata.into_iter().map(|(key, value)| (key.to_owned(), value)).collect(),
+        }
+    }
+}
+///
+/// Output of the single [Stage](super::Stage) within the [Pipeline](super::Pipeline)
+#[derive(Debug, Clone)]
+pub struct StageResult {
+    /// Name of the stage
+    pub name: String,
+    /// Output of the stage
+    pub output: StageOutput,
+    /// Time spent by the stage
+    pub elapsed: Duration,
+}
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod tuning;
+mod tuning_param;
+pub use tuning::*;
+pub use tuning_param::*;
@@ -0,0 +1,109 @@
+use opencv::highgui;
+use sal_core::error::Error;
+use serde_json::Value;
+use crate::conf::PipelineConf;
+use super::TuningParam;
+///
+/// Interactive tuning of the stage parameters
+/// 
+/// Each numeric parameter of the [PipelineConf] stages and of the `detecting-contours` section
+/// is exposed as the `highgui` trackbar in the [Tuning::WINDOW] window, ranges are described in [TuningParam];
+/// the config with the current trackbar values is returned by [Tuning::poll]
+pub struct Tuning {
+    value: Value,
+    params: Vec<TuningParam>,
+    positions: Vec<i32>,
+}
+//
+//
+impl Tuning {
+    ///
+    /// Name of the window, containing trackbars
+    pub const WINDOW: &'static str = "Parameters";
+    ///
+    /// Returns [Tuning] new instance, trackbars are created for the `conf`
+    pub fn new(conf: &PipelineConf) -> Result<Self, Error> {
+        let error = Error::new("Tuning", "new");
+        let value = serde_json::to_value(conf)
+            .map_err(|err| error.pass(err.to_string()))?;
+        let mut params = vec![];
+        if let Some(stages) = value.get("stages").and_then(Value::as_array) {
+            for (index, stage) in stages.iter().enumerate() {
+                Self::collect(stage, &format!("/stages/{index}"), &format!("{index}:"), &mut params);
+            }
+        }
+        if let Some(contours) = value.get("detecting-contours") {
+            Self::collect(contours, "/detecting-contours", "contours:", &mut params);
+        }
+        highgui::named_window(Self::WINDOW, highgui::WINDOW_NORMAL)
+            .map_err(|err| error.pass(err.to_string()))?;
+        let mut positions = vec![];
+        for param in &params {
+            let pos = value.pointer(&param.pointer).map_or(0, |value| param.pos(value));
+            highgui::create_trackbar(&param.name, Self::WINDOW, None, param.count, None)
+                .map_err(|err| error.pass(format!("Trackbar '{}' error: {}", param.name, err)))?;
+            highgui::set_trackbar_pos(&param.name, Self::WINDOW, pos)
+                .map_err(|err| error.pass(format!("Trackbar '{}' error: {}", param.name, err)))?;
+            positions.push(pos);
+        }
+        log::debug!("Tuning.new | {} parameters: {:?}", params.len(), params.iter().map(|param| &param.name).collect::<Vec<_>>());
+        Ok(Self { value, params, positions })
+    }
+    ///
+    /// Collects the numeric parameters of the `value` into the `params`
+    /// - `pointer` - JSON pointer of the `value`
+    /// - `name` - trackbar name prefix of the `value`
+    fn collect(value: &Value, pointer: &str, name: &str, params: &mut Vec<TuningParam>) {
+        let sep = if name.ends_with(':') { "" } else { "." };
+        match value {
+            Value::Object(map) => for (key, value) in map {
+                let pointer = format!("{pointer}/{key}");
+                let name = format!("{name}{sep}{key}");
+                match value {
+                    Value::Number(_) => params.extend(TuningParam::new(name, pointer, value)),
+                    _ => Self::collect(value, &pointer, &name, params),
+                }
+            },
+            Value::Array(items) => for (index, value) in items.iter().enumerate() {
+                Self::collect(value, &format!("{pointer}/{index}"), &format!("{name}{sep}{index}"), params);
+            },
+            _ => {}
+        }
+    }
+    ///
+    /// Returns the config with the current trackbar values if any of them changed,
+    /// `None` if nothing changed or the new values are invalid
+    pub fn poll(&mut self) -> Result<Option<PipelineConf>, Error> {
+        let error = Error::new("Tuning", "poll");
+        let mut changed = false;
+        for (param, position) in self.params.iter().zip(self.positions.iter_mut()) {
+            let pos = highgui::get_trackbar_pos(&param.name, Self::WINDOW)
+                .map_err(|err| error.pass(format!("Trackbar '{}' error: {}", param.name, err)))?;
+            if pos != *position {
+                *position = pos;
+                if let Some(value) = self.value.pointer_mut(&param.pointer) {
+                    *value = param.value(pos);
+                    log::debug!("Tuning.poll | {}: {}", param.name, value);
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            return Ok(None);
+        }
+        let conf: PipelineConf = match serde_json::from_value(self.value.clone()) {
+            Ok(conf) => conf,
+            Err(err) => {
+                log::warn!("Tuning.poll | Invalid parameters: {}", err);
+                return Ok(None);
+            }
+        };
+        match conf.validate() {
+            Ok(_) => Ok(Some(conf)),
+            Err(err) => {
+                log::warn!("Tuning.poll | Invalid parameters: {:?}", err);
+                Ok(None)
+            }
+        }
+    }
+}
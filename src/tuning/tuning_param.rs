@@ -0,0 +1,137 @@
+use serde_json::Value;
+///
+/// Numeric parameter of the stage config, exposed as the trackbar
+/// 
+/// The trackbar position `pos` maps to the value `min + pos * step`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuningParam {
+    /// Name of the trackbar, `<stage index>:<key path>` or `contours:<key path>`
+    pub name: String,
+    /// JSON pointer of the value within the serialized config
+    pub pointer: String,
+    /// Value at the trackbar position 0
+    pub min: f64,
+    /// Value change per trackbar position
+    pub step: f64,
+    /// Maximum position of the trackbar
+    pub count: i32,
+    /// Value is an integer
+    pub integer: bool,
+}
+//
+//
+impl TuningParam {
+    ///
+    /// Returns [TuningParam] for the `value`, `None` if the value isn't a number
+    /// - `name` - name of the trackbar
+    /// - `pointer` - JSON pointer of the value
+    pub fn new(name: String, pointer: String, value: &Value) -> Option<Self> {
+        let integer = value.is_i64() || value.is_u64();
+        let current = value.as_f64()?;
+        let (min, max, step) = Self::range(&pointer, current, integer);
+        Some(Self {
+            name,
+            pointer,
+            min,
+            step,
+            count: ((max - min) / step).round().max(1.0) as i32,
+            integer,
+        })
+    }
+    ///
+    /// Returns the range `(min, max, step)` of the parameter with the JSON `pointer`,
+    /// the key is looked up along with it's parent, so `kernel/width` and `roi/width` differ;
+    /// derived from the `current` value for unknown keys and for the `detecting-contours` section,
+    /// since it's values are defined by the `frdm_tools`
+    fn range(pointer: &str, current: f64, integer: bool) -> (f64, f64, f64) {
+        let mut path = pointer.rsplit('/');
+        let key = path.next().unwrap_or_default();
+        let parent = path.next().unwrap_or_default();
+        let key = if pointer.starts_with("/detecting-contours/") { "" } else { key };
+        match (parent, key) {
+            ("roi", "x" | "y") => (0.0, 4096.0, 1.0),
+            ("roi", "width" | "height") => (1.0, 4096.0, 1.0),
+            ("kernel", "width" | "height") => (1.0, 31.0, 1.0),
+            (_, "mid" | "factor" | "background-ratio" | "decision-threshold" | "replace-rate" | "propagation-rate") => (0.0, 1.0, 0.01),
+            (_, "scene-change") => (0.01, 2.0, 0.01),
+            (_, "clip-hist-percent") => (0.0, 20.0, 0.1),
+            (_, "learning-rate" | "warm-up-learning-rate") => (-1.0, 1.0, 0.01),
+            (_, "history" | "initialization-frames") => (1.0, 1000.0, 1.0),
+            (_, "nmixtures") => (1.0, 10.0, 1.0),
+            (_, "noise-sigma") => (0.0, 50.0, 0.5),
+            (_, "var-threshold") => (1.0, 100.0, 0.5),
+            (_, "dist2-threshold") => (10.0, 2000.0, 10.0),
+            (_, "min-pixel-stability" | "max-pixel-stability") => (1.0, 1800.0, 1.0),
+            (_, "iterations") => (1.0, 10.0, 1.0),
+            (_, "min-area") => (1.0, 5000.0, 1.0),
+            _ if integer => (0.0, (current * 2.0).max(10.0), 1.0),
+            _ => (0.0, (current.abs() * 2.0).max(1.0), (current.abs() * 2.0).max(1.0) / 100.0),
+        }
+    }
+    ///
+    /// Returns the trackbar position of the `value`
+    pub fn pos(&self, value: &Value) -> i32 {
+        let value = value.as_f64().unwrap_or(self.min);
+        (((value - self.min) / self.step).round() as i32).clamp(0, self.count)
+    }
+    ///
+    /// Returns the value at the trackbar position `pos`
+    pub fn value(&self, pos: i32) -> Value {
+        let value = self.min + (pos as f64) * self.step;
+        if self.integer {
+            Value::from(value.round() as i64)
+        } else {
+            // Rounded to the step precision, keeping the dumped config readable
+            Value::from((value / self.step).round() * self.step)
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use super::TuningParam;
+    ///
+    /// Returns [TuningParam] of the `value` at the JSON `pointer`
+    fn param(pointer: &str, value: Value) -> TuningParam {
+        TuningParam::new(pointer.to_owned(), pointer.to_owned(), &value).unwrap()
+    }
+    #[test]
+    fn not_number() {
+        assert_eq!(TuningParam::new("name".to_owned(), "/stages/0/kind".to_owned(), &json!("rect")), None);
+    }
+    #[test]
+    fn range_by_parent() {
+        let kernel = param("/stages/3/kernel/width", json!(3));
+        assert_eq!((kernel.min, kernel.step, kernel.count), (1.0, 1.0, 30));
+        let roi = param("/stages/0/roi/width", json!(200));
+        assert_eq!((roi.min, roi.step, roi.count), (1.0, 1.0, 4095));
+        let mid = param("/stages/0/mid", json!(0.5));
+        assert_eq!((mid.min, mid.step, mid.count), (0.0, 0.01, 100));
+        assert!(!mid.integer);
+    }
+    #[test]
+    fn range_of_unknown() {
+        let integer = param("/detecting-contours/min-area", json!(40));
+        assert_eq!((integer.min, integer.step, integer.count), (0.0, 1.0, 80));
+        let float = param("/detecting-contours/epsilon", json!(2.5));
+        assert_eq!((float.min, float.step, float.count), (0.0, 0.05, 100));
+    }
+    #[test]
+    fn pos_clamped() {
+        let mid = param("/stages/0/mid", json!(0.5));
+        assert_eq!(mid.pos(&json!(0.5)), 50);
+        assert_eq!(mid.pos(&json!(-3.0)), 0);
+        assert_eq!(mid.pos(&json!(7.0)), mid.count);
+        assert_eq!(mid.pos(&json!("text")), 0);
+    }
+    #[test]
+    fn value_pos_round_trip() {
+        for param in [param("/stages/0/mid", json!(0.5)), param("/stages/3/kernel/width", json!(3)), param("/stages/1/clip-hist-percent", json!(3.0))] {
+            for pos in 0..=param.count {
+                let value = param.value(pos);
+                assert_eq!(value.is_i64(), param.integer, "{}: {}", param.name, value);
+                assert_eq!(param.pos(&value), pos, "{}: {}", param.name, value);
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod viewer;
+mod viewer_key;
+pub use viewer::*;
+pub use viewer_key::*;
@@ -0,0 +1,228 @@
+use std::{collections::{BTreeSet, VecDeque}, path::PathBuf};
+use opencv::{core::Vector, highgui, imgcodecs};
+use sal_core::error::Error;
+use crate::mosaic::{Mosaic, Tile};
+use super::ViewerKey;
+///
+/// Interactive viewer of the processed frames
+/// 
+/// Keeps the outputs of the recent frames, so the viewer can step back over them,
+/// the source itself is read forward only; keys are described in [ViewerKey]
+/// 
+/// The outputs are shown as the single [Mosaic] or each in it's own window,
+/// hidden outputs are excluded from the mosaic and their windows closed
+/// 
+/// The outputs are kept shrunk to the mosaic tile height, about `height × width × 3` bytes of the tile each;
+/// in the windows mode they are kept in full resolution, so the `capacity` costs
+/// `capacity × outputs × frame size`, e.g. 2 GB for 50 frames of 7 BGR outputs at 1920×1080
+pub struct Viewer {
+    mosaic: Mosaic,
+    windows: bool,
+    snapshots: PathBuf,
+    capacity: usize,
+    frames: VecDeque<(String, Vec<Tile>)>,
+    position: usize,
+    paused: bool,
+    seeking: bool,
+    pending: bool,
+    exhausted: bool,
+    hidden: BTreeSet<usize>,
+    shown: BTreeSet<String>,
+}
+//
+//
+impl Viewer {
+    ///
+    /// Window of the mosaic
+    const MOSAIC: &'static str = "Mosaic";
+    ///
+    /// Delay between the frames during the playback, ms
+    const DELAY: i32 = 100;
+    ///
+    /// Returns [Viewer] new instance
+    /// - `mosaic` - layout of the mosaic, shown and saved
+    /// - `windows` - each output shown in it's own window instead of the mosaic
+    /// - `capacity` - number of the recent frames kept to step back over
+    /// - `snapshots` - directory, the mosaic saved into
+    pub fn new(mosaic: Mosaic, windows: bool, capacity: usize, snapshots: impl Into<PathBuf>) -> Self {
+        Self {
+            mosaic,
+            windows,
+            snapshots: snapshots.into(),
+            capacity: capacity.max(1),
+            frames: VecDeque::new(),
+            position: 0,
+            paused: false,
+            seeking: false,
+            pending: false,
+            exhausted: false,
+            hidden: BTreeSet::new(),
+            shown: BTreeSet::new(),
+        }
+    }
+    ///
+    /// Returns true if the next frame of the source is requested
+    pub fn wants_frame(&self) -> bool {
+        !self.exhausted && (self.seeking || self.pending || (!self.paused && self.at_latest()))
+    }
+    ///
+    /// Returns true if the last frame is searched, the frames aren't shown meanwhile
+    pub fn seeking(&self) -> bool {
+        self.seeking
+    }
+    ///
+    /// Returns the delay to wait for the key, ms, 0 - wait forever
+    pub fn delay(&self) -> i32 {
+        match (self.seeking, self.paused || self.exhausted) {
+            (true, _) => 1,
+            (false, true) => 0,
+            (false, false) => Self::DELAY,
+        }
+    }
+    ///
+    /// Adds the outputs of the new frame, it becomes the current one,
+    /// the outputs are shrunk to the mosaic tile height unless shown in the windows
+    pub fn push(&mut self, name: String, tiles: Vec<Tile>) -> Result<(), Error> {
+        let tiles = if self.windows {
+            tiles
+        } else {
+            tiles.iter().map(|tile| self.mosaic.shrink(tile)).collect::<Result<_, _>>()?
+        };
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back((name, tiles));
+        self.position = self.frames.len() - 1;
+        self.pending = false;
+        Ok(())
+    }
+    ///
+    /// Marks the source exhausted, the viewer stays on the last frame
+    pub fn exhausted(&mut self) {
+        if !self.exhausted {
+            log::info!("Viewer.exhausted | Source exhausted, press 'q' to quit");
+        }
+        self.exhausted = true;
+        self.seeking = false;
+        self.pending = false;
+    }
+    ///
+    /// Returns true if the current frame is the latest one
+    fn at_latest(&self) -> bool {
+        self.position + 1 >= self.frames.len()
+    }
+    ///
+    /// Returns visible outputs of the current frame
+    fn visible(&self) -> Vec<Tile> {
+        self.frames.get(self.position).map_or(vec![], |(_, tiles)| {
+            tiles.iter().enumerate()
+                .filter(|(index, _)| !self.hidden.contains(index))
+                .map(|(_, tile)| tile.clone())
+                .collect()
+        })
+    }
+    ///
+    /// Shows the current frame
+    pub fn show(&mut self) -> Result<(), Error> {
+        let error = Error::new("Viewer", "show");
+        let tiles = self.visible();
+        let windows: BTreeSet<String> = if self.windows {
+            tiles.iter().map(|tile| tile.name.clone()).collect()
+        } else {
+            BTreeSet::from([Self::MOSAIC.to_owned()])
+        };
+        for window in self.shown.difference(&windows) {
+            highgui::destroy_window(window)
+                .map_err(|err| error.pass(err.to_string()))?;
+        }
+        for window in windows.difference(&self.shown) {
+            highgui::named_window(window, highgui::WINDOW_NORMAL)
+                .map_err(|err| error.pass(err.to_string()))?;
+        }
+        self.shown = windows;
+        if tiles.is_empty() {
+            return Ok(());
+        }
+        if self.windows {
+            for tile in &tiles {
+                highgui::imshow(&tile.name, &tile.mat)
+                    .map_err(|err| error.pass(err.to_string()))?;
+            }
+        } else {
+            let img = self.mosaic.render(&tiles)?;
+            highgui::imshow(Self::MOSAIC, &img)
+                .map_err(|err| error.pass(err.to_string()))?;
+        }
+        Ok(())
+    }
+    ///
+    /// Handles the `key` code, returned by `highgui::wait_key_ex`,
+    /// returns false if the viewer should be closed
+    pub fn key(&mut self, key: i32) -> Result<bool, Error> {
+        let key = ViewerKey::from_code(key);
+        if key == ViewerKey::None {
+            return Ok(true);
+        }
+        log::debug!("Viewer.key | {:?}", key);
+        // Any key stops the search of the last frame
+        if self.seeking {
+            self.seeking = false;
+            self.paused = true;
+            return Ok(key != ViewerKey::Quit);
+        }
+        match key {
+            ViewerKey::Pause => self.paused = !self.paused,
+            ViewerKey::Forward => {
+                self.paused = true;
+                if self.at_latest() {
+                    self.pending = true;
+                } else {
+                    self.position += 1;
+                }
+            }
+            ViewerKey::Back => {
+                self.paused = true;
+                self.position = self.position.saturating_sub(1);
+            }
+            ViewerKey::First => {
+                self.paused = true;
+                self.position = 0;
+            }
+            ViewerKey::Last => {
+                self.paused = true;
+                self.position = self.frames.len().saturating_sub(1);
+                self.seeking = !self.exhausted;
+            }
+            ViewerKey::Save => self.save()?,
+            ViewerKey::Toggle(index) => {
+                if !self.hidden.remove(&index) {
+                    self.hidden.insert(index);
+                }
+            }
+            ViewerKey::Quit => return Ok(false),
+            ViewerKey::None => {}
+        }
+        Ok(true)
+    }
+    ///
+    /// Saves the mosaic of the current frame into `snapshots/<frame name>.png`
+    fn save(&self) -> Result<(), Error> {
+        let error = Error::new("Viewer", "save");
+        let Some((name, _)) = self.frames.get(self.position) else {
+            return Ok(());
+        };
+        let tiles = self.visible();
+        if tiles.is_empty() {
+            log::warn!("Viewer.save | All outputs are hidden, nothing to save");
+            return Ok(());
+        }
+        let img = self.mosaic.render(&tiles)?;
+        std::fs::create_dir_all(&self.snapshots)
+            .map_err(|err| error.pass(format!("Create dir '{}' error: {}", self.snapshots.display(), err)))?;
+        let path = self.snapshots.join(name).with_extension("png");
+        imgcodecs::imwrite(&path.to_string_lossy(), &img, &Vector::new())
+            .map_err(|err| error.pass(format!("Write '{}' error: {}", path.display(), err)))?;
+        log::info!("Viewer.save | Saved: {}", path.display());
+        Ok(())
+    }
+}
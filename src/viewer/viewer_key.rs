@@ -0,0 +1,113 @@
+///
+/// Keyboard command of the [Viewer](super::Viewer)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewerKey {
+    /// `space` / `p` - pause / resume
+    Pause,
+    /// `d` / `.` / `Right` - one frame forward
+    Forward,
+    /// `a` / `,` / `Left` - one frame back
+    Back,
+    /// `f` / `Home` - first frame
+    First,
+    /// `l` / `End` - last frame, the source processed to the end
+    Last,
+    /// `s` - save the current mosaic as PNG
+    Save,
+    /// `1`..`9` - show / hide the output with the index `0`..`8`
+    Toggle(usize),
+    /// `q` / `Esc` - quit
+    Quit,
+    /// Key isn't bound or nothing pressed
+    None,
+}
+//
+//
+impl ViewerKey {
+    ///
+    /// Modifier bits (Shift, CapsLock, Ctrl, NumLock), reported by GTK along with the key code
+    const GTK_MODIFIERS: i32 = 0x00ff0000;
+    ///
+    /// Returns [ViewerKey] of the `key` code, returned by `highgui::wait_key_ex`,
+    /// [ViewerKey::None] if the key isn't bound or nothing pressed
+    pub fn from_code(key: i32) -> Self {
+        // Special keys as reported by GTK, Qt and Windows backends
+        const HOME: [i32; 3] = [0xff50, 0x1000010, 0x240000];
+        const END: [i32; 3] = [0xff57, 0x1000011, 0x230000];
+        const LEFT: [i32; 3] = [0xff51, 0x1000012, 0x250000];
+        const RIGHT: [i32; 3] = [0xff53, 0x1000014, 0x270000];
+        if key < 0 {
+            return Self::None;
+        }
+        // The code is matched as is first, Windows special keys occupy the GTK modifier bits,
+        // then without the GTK modifiers; Qt codes keep their 0x01000000 bit, so never taken for ASCII
+        let code = key & !Self::GTK_MODIFIERS;
+        for (codes, cmd) in [(HOME, Self::First), (END, Self::Last), (LEFT, Self::Back), (RIGHT, Self::Forward)] {
+            if codes.contains(&key) || codes.contains(&code) {
+                return cmd;
+            }
+        }
+        if code > 0xff {
+            return Self::None;
+        }
+        let code = char::from(code as u8);
+        match code {
+            ' ' | 'p' => Self::Pause,
+            'd' | '.' => Self::Forward,
+            'a' | ',' => Self::Back,
+            'f' => Self::First,
+            'l' => Self::Last,
+            's' => Self::Save,
+            '1'..='9' => Self::Toggle(code as usize - '1' as usize),
+            'q' | '\x1b' => Self::Quit,
+            _ => Self::None,
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::ViewerKey;
+    #[test]
+    fn nothing() {
+        assert_eq!(ViewerKey::from_code(-1), ViewerKey::None);
+    }
+    #[test]
+    fn ascii() {
+        assert_eq!(ViewerKey::from_code('q' as i32), ViewerKey::Quit);
+        assert_eq!(ViewerKey::from_code(27), ViewerKey::Quit);
+        assert_eq!(ViewerKey::from_code(' ' as i32), ViewerKey::Pause);
+        assert_eq!(ViewerKey::from_code('1' as i32), ViewerKey::Toggle(0));
+        assert_eq!(ViewerKey::from_code('9' as i32), ViewerKey::Toggle(8));
+        assert_eq!(ViewerKey::from_code('x' as i32), ViewerKey::None);
+    }
+    #[test]
+    fn gtk_modifiers() {
+        // NumLock, CapsLock and Shift + CapsLock
+        assert_eq!(ViewerKey::from_code(0x100000 | 'q' as i32), ViewerKey::Quit);
+        assert_eq!(ViewerKey::from_code(0x20000 | 27), ViewerKey::Quit);
+        assert_eq!(ViewerKey::from_code(0x30000 | 'q' as i32), ViewerKey::Quit);
+        assert_eq!(ViewerKey::from_code(0x100000 | 0xff51), ViewerKey::Back);
+    }
+    #[test]
+    fn special() {
+        // GTK, Qt and Windows Left
+        assert_eq!(ViewerKey::from_code(0xff51), ViewerKey::Back);
+        assert_eq!(ViewerKey::from_code(0x1000012), ViewerKey::Back);
+        assert_eq!(ViewerKey::from_code(0x250000), ViewerKey::Back);
+        assert_eq!(ViewerKey::from_code(0x270000), ViewerKey::Forward);
+    }
+    #[test]
+    fn shift() {
+        // Qt and GTK Shift alone
+        assert_eq!(ViewerKey::from_code(0x1000020), ViewerKey::None);
+        assert_eq!(ViewerKey::from_code(0xffe1), ViewerKey::None);
+    }
+    #[test]
+    fn function_keys() {
+        // Qt F2..F10 and GTK F2
+        for code in 0x1000031..=0x1000039 {
+            assert_eq!(ViewerKey::from_code(code), ViewerKey::None, "code: {:#x}", code);
+        }
+        assert_eq!(ViewerKey::from_code(0xffbf), ViewerKey::None);
+    }
+}